@@ -0,0 +1,204 @@
+/*
+  This is free and unencumbered software released into the public domain.
+
+  Anyone is free to copy, modify, publish, use, compile, sell, or
+  distribute this software, either in source code form or as a compiled
+  binary, for any purpose, commercial or non-commercial, and by any
+  means.
+
+  In jurisdictions that recognize copyright laws, the author or authors
+  of this software dedicate any and all copyright interest in the
+  software to the public domain. We make this dedication for the benefit
+  of the public at large and to the detriment of our heirs and
+  successors. We intend this dedication to be an overt act of
+  relinquishment in perpetuity of all present and future rights to this
+  software under copyright law.
+
+  THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+  EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+  MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+  IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+  OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+  ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+  OTHER DEALINGS IN THE SOFTWARE.
+
+  For more information, please refer to <http://unlicense.org/>
+*/
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include <uv.h>
+
+#include "cassandra.h"
+
+/*
+ * Compares the time of reading a few rows of a large page with rows built
+ * eagerly (the default) and lazily (see cass_statement_set_lazy_rows()).
+ */
+
+#define NUM_ROWS 10000
+#define NUM_ROWS_READ 10
+#define NUM_CONCURRENT_REQUESTS 1000
+#define NUM_ITERATIONS 100
+
+void print_error(CassFuture* future) {
+  const char* message;
+  size_t message_length;
+  cass_future_error_message(future, &message, &message_length);
+  fprintf(stderr, "Error: %.*s\n", (int)message_length, message);
+}
+
+CassCluster* create_cluster(const char* hosts) {
+  CassCluster* cluster = cass_cluster_new();
+  cass_cluster_set_contact_points(cluster, hosts);
+  return cluster;
+}
+
+CassError connect_session(CassSession* session, const CassCluster* cluster) {
+  CassError rc = CASS_OK;
+  CassFuture* future = cass_session_connect(session, cluster);
+
+  cass_future_wait(future);
+  rc = cass_future_error_code(future);
+  if (rc != CASS_OK) {
+    print_error(future);
+  }
+  cass_future_free(future);
+
+  return rc;
+}
+
+CassError execute_query(CassSession* session, const char* query) {
+  CassError rc = CASS_OK;
+  CassFuture* future = NULL;
+  CassStatement* statement = cass_statement_new(query, 0);
+
+  future = cass_session_execute(session, statement);
+  cass_future_wait(future);
+
+  rc = cass_future_error_code(future);
+  if (rc != CASS_OK) {
+    print_error(future);
+  }
+
+  cass_future_free(future);
+  cass_statement_free(statement);
+
+  return rc;
+}
+
+void insert_into_lazy_rows(CassSession* session) {
+  const char* query = "INSERT INTO lazy_rows (key, value, tags) VALUES (?, ?, ?);";
+  CassFuture* futures[NUM_CONCURRENT_REQUESTS];
+  size_t i, j;
+
+  for (i = 0; i < NUM_ROWS; i += NUM_CONCURRENT_REQUESTS) {
+    for (j = 0; j < NUM_CONCURRENT_REQUESTS; ++j) {
+      char value_buffer[256];
+      CassStatement* statement = cass_statement_new(query, 3);
+      CassCollection* tags = cass_collection_new(CASS_COLLECTION_TYPE_LIST, 2);
+
+      cass_statement_bind_int32(statement, 0, (cass_int32_t)(i + j));
+      sprintf(value_buffer, "value %u", (unsigned int)(i + j));
+      cass_statement_bind_string(statement, 1, value_buffer);
+      cass_collection_append_string(tags, "a");
+      cass_collection_append_string(tags, "b");
+      cass_statement_bind_collection(statement, 2, tags);
+
+      futures[j] = cass_session_execute(session, statement);
+
+      cass_collection_free(tags);
+      cass_statement_free(statement);
+    }
+
+    for (j = 0; j < NUM_CONCURRENT_REQUESTS; ++j) {
+      if (cass_future_error_code(futures[j]) != CASS_OK) {
+        print_error(futures[j]);
+      }
+      cass_future_free(futures[j]);
+    }
+  }
+}
+
+/* Returns the average time (in microseconds) of a select reading NUM_ROWS_READ rows. */
+double select_from_lazy_rows(CassSession* session, cass_bool_t lazy_rows) {
+  CassStatement* statement = cass_statement_new("SELECT * FROM lazy_rows", 0);
+  uint64_t start = uv_hrtime();
+  int i;
+
+  cass_statement_set_lazy_rows(statement, lazy_rows);
+
+  for (i = 0; i < NUM_ITERATIONS; ++i) {
+    int read = 0;
+    const CassResult* result;
+    CassIterator* iterator;
+    CassFuture* future = cass_session_execute(session, statement);
+
+    if (cass_future_error_code(future) != CASS_OK) {
+      print_error(future);
+      cass_future_free(future);
+      break;
+    }
+
+    result = cass_future_get_result(future);
+    iterator = cass_iterator_from_result(result);
+    cass_future_free(future);
+
+    while (read < NUM_ROWS_READ && cass_iterator_next(iterator)) {
+      const char* value;
+      size_t value_length;
+      const CassRow* row = cass_iterator_get_row(iterator);
+      cass_value_get_string(cass_row_get_column(row, 1), &value, &value_length);
+      ++read;
+    }
+
+    cass_iterator_free(iterator);
+    cass_result_free(result);
+  }
+
+  cass_statement_free(statement);
+
+  return (double)(uv_hrtime() - start) / NUM_ITERATIONS / 1000.0;
+}
+
+int main(int argc, char* argv[]) {
+  double eager_time, lazy_time;
+  CassCluster* cluster = NULL;
+  CassSession* session = cass_session_new();
+  char* hosts = "127.0.0.1";
+  if (argc > 1) {
+    hosts = argv[1];
+  }
+  cluster = create_cluster(hosts);
+
+  if (connect_session(session, cluster) != CASS_OK) {
+    cass_cluster_free(cluster);
+    cass_session_free(session);
+    return -1;
+  }
+
+  execute_query(session, "CREATE KEYSPACE IF NOT EXISTS examples WITH replication = { \
+                           'class': 'SimpleStrategy', 'replication_factor': '1' };");
+
+  execute_query(session, "CREATE TABLE IF NOT EXISTS examples.lazy_rows (key int, \
+                                               value text, tags list<text>, \
+                                               PRIMARY KEY (key));");
+
+  execute_query(session, "USE examples");
+
+  insert_into_lazy_rows(session);
+
+  /* Warm up, so both modes are measured with the same state of the cluster. */
+  select_from_lazy_rows(session, cass_false);
+
+  eager_time = select_from_lazy_rows(session, cass_false);
+  lazy_time = select_from_lazy_rows(session, cass_true);
+  printf("Reading %d of %d rows (microseconds per select): eager %.1f lazy %.1f\n", NUM_ROWS_READ,
+         NUM_ROWS, eager_time, lazy_time);
+
+  cass_cluster_free(cluster);
+  cass_session_free(session);
+
+  return 0;
+}
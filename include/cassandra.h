@@ -2025,6 +2025,23 @@ CASS_EXPORT void
 cass_cluster_set_connect_timeout(CassCluster* cluster,
                                  unsigned timeout_ms);
 
+/**
+ * Sets the deadline for connecting a session, including connecting to
+ * the nodes and fetching the metadata of the cluster. If the session is not
+ * connected before the deadline, the connect future fails with
+ * CASS_ERROR_LIB_REQUEST_TIMED_OUT.
+ *
+ * <b>Default:</b> 0 (no deadline)
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] deadline_ms Connect deadline in milliseconds. 0 disables the deadline.
+ */
+CASS_EXPORT void
+cass_cluster_set_session_connect_deadline(CassCluster* cluster,
+                                          unsigned deadline_ms);
+
 /**
  * Sets the timeout for waiting for a response from a node.
  *
@@ -2144,6 +2161,46 @@ cass_cluster_set_credentials_n(CassCluster* cluster,
                                const char* password,
                                size_t password_length);
 
+/**
+ * Enables GSSAPI authentication for DSE clusters.
+ *
+ * <b>Note:</b> GSSAPI authentication is not supported, so this function
+ * always returns CASS_ERROR_LIB_NOT_IMPLEMENTED.
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] service
+ * @param[in] principal
+ * @return CASS_ERROR_LIB_NOT_IMPLEMENTED
+ */
+CASS_EXPORT CassError
+cass_cluster_set_dse_gssapi_authenticator(CassCluster* cluster,
+                                          const char* service,
+                                          const char* principal);
+
+/**
+ * Same as cass_cluster_set_dse_gssapi_authenticator(), but with lengths for
+ * string parameters.
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] service
+ * @param[in] service_length
+ * @param[in] principal
+ * @param[in] principal_length
+ * @return CASS_ERROR_LIB_NOT_IMPLEMENTED
+ *
+ * @see cass_cluster_set_dse_gssapi_authenticator()
+ */
+CASS_EXPORT CassError
+cass_cluster_set_dse_gssapi_authenticator_n(CassCluster* cluster,
+                                            const char* service,
+                                            size_t service_length,
+                                            const char* principal,
+                                            size_t principal_length);
+
 /**
  * Configures the cluster to use round-robin load balancing.
  *
@@ -2219,6 +2276,27 @@ cass_cluster_set_load_balance_dc_aware_n(CassCluster* cluster,
                                          unsigned used_hosts_per_remote_dc,
                                          cass_bool_t allow_remote_dcs_for_local_cl);
 
+/**
+ * Sets whether the DC-aware load balancing policy may fail over to nodes
+ * of remote DCs when no local DC nodes are available. When disabled, requests
+ * are pinned strictly to the local DC.
+ *
+ * <b>Note:</b> The DC-aware policy must be set before calling this function.
+ *
+ * <b>Default:</b> cass_true (or the value of allow_remote_dcs_for_local_cl
+ * passed to cass_cluster_set_load_balance_dc_aware())
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] enabled
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the cluster
+ * does not use the DC-aware load balancing policy.
+ */
+CASS_EXPORT CassError
+cass_cluster_set_load_balance_dc_aware_permit_dc_failover(CassCluster* cluster,
+                                                          cass_bool_t enabled);
+
 /**
  * Configures the cluster to use token-aware request routing or not.
  *
@@ -2766,6 +2844,9 @@ cass_cluster_set_prepare_on_up_or_add_host(CassCluster* cluster,
  *
  * <b>Default:</b> cass_false
  *
+ * <b>Note:</b> Custom STARTUP options are not supported, so enabling
+ * the option returns CASS_ERROR_LIB_NOT_IMPLEMENTED.
+ *
  * @cassandra{3.0.16+}
  * @cassandra{3.11.2+}
  * @cassandra{4.0+}
@@ -2774,6 +2855,7 @@ cass_cluster_set_prepare_on_up_or_add_host(CassCluster* cluster,
  *
  * @param[in] cluster
  * @param[in] enabled
+ * @return CASS_OK if disabled, CASS_ERROR_LIB_NOT_IMPLEMENTED if enabled.
  */
 CASS_EXPORT CassError
 cass_cluster_set_no_compact(CassCluster* cluster,
@@ -2869,6 +2951,9 @@ cass_cluster_set_cloud_secure_connection_bundle_no_ssl_lib_init_n(CassCluster* c
  * that can aid in debugging issues with larger clusters where there are a lot
  * of client (or application) connections.
  *
+ * <b>Note:</b> Not supported by this driver - the application name is not
+ * sent to the server and a warning is logged instead.
+ *
  * @public @memberof CassCluster
  *
  * @param[in] cluster
@@ -2957,6 +3042,25 @@ CASS_EXPORT void
 cass_cluster_set_monitor_reporting_interval(CassCluster* cluster,
                                             unsigned interval_secs);
 
+/**
+ * Sets the interval at which the session's metrics are refreshed in the
+ * background. Metrics returned by cass_session_get_metrics() are the ones
+ * of the latest refresh.
+ *
+ * <b>Default:</b> Metrics are computed on each call to cass_session_get_metrics().
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] refresh_interval Interval in milliseconds (must be greater than 0).
+ * @return CASS_OK if successful, otherwise CASS_ERROR_LIB_BAD_PARAMS.
+ *
+ * @see cass_session_get_metrics()
+ */
+CASS_EXPORT CassError
+cass_cluster_set_histogram_refresh_interval(CassCluster* cluster,
+                                            unsigned refresh_interval);
+
 /**
  * Sets the preferred compression algorithm.
  * <b>Default:</b> no compression.
@@ -3206,6 +3310,24 @@ cass_session_get_speculative_execution_metrics(const CassSession* session,
 CASS_EXPORT CassUuid
 cass_session_get_client_id(CassSession* session);
 
+/**
+ * Gets the trace events of a request, which was executed with tracing
+ * enabled. The events are returned as rows of the "system_traces.events"
+ * table.
+ *
+ * @public @memberof CassSession
+ *
+ * @param[in] session
+ * @param[in] tracing_id The tracing ID of the request.
+ * @return A future that must be freed.
+ *
+ * @see cass_statement_set_tracing()
+ * @see cass_future_tracing_id()
+ */
+CASS_EXPORT CassFuture*
+cass_session_get_trace(CassSession* session,
+                       CassUuid tracing_id);
+
 /***********************************************************************************
  *
  * Schema Metadata
@@ -4633,6 +4755,43 @@ cass_ssl_add_trusted_cert_n(CassSsl* ssl,
                             const char* cert,
                             size_t cert_length);
 
+/**
+ * Adds all trusted certificates from a directory. Each file with the
+ * ".pem" extension is expected to contain a PEM formatted certificate.
+ * Files are added in the order of their names, and certificates added
+ * before an invalid file is found are kept.
+ *
+ * @public @memberof CassSsl
+ *
+ * @param[in] ssl
+ * @param[in] dir Path to the directory with the certificates
+ * @return CASS_OK if successful, CASS_ERROR_SSL_INVALID_CERT if any of
+ * the files is not a valid certificate, otherwise an error occurred
+ *
+ * @see cass_ssl_add_trusted_cert()
+ */
+CASS_EXPORT CassError
+cass_ssl_add_trusted_cert_dir(CassSsl* ssl,
+                              const char* dir);
+
+/**
+ * Same as cass_ssl_add_trusted_cert_dir(), but with lengths for string
+ * parameters.
+ *
+ * @public @memberof CassSsl
+ *
+ * @param[in] ssl
+ * @param[in] dir
+ * @param[in] dir_length
+ * @return same as cass_ssl_add_trusted_cert_dir()
+ *
+ * @see cass_ssl_add_trusted_cert_dir()
+ */
+CASS_EXPORT CassError
+cass_ssl_add_trusted_cert_dir_n(CassSsl* ssl,
+                                const char* dir,
+                                size_t dir_length);
+
 /**
  * Sets verification performed on the peer's certificate.
  *
@@ -5280,6 +5439,29 @@ cass_statement_set_paging_state_token(CassStatement* statement,
                                       const char* paging_state,
                                       size_t paging_state_size);
 
+/**
+ * Same as cass_statement_set_paging_state_token(), but the paging state
+ * is given as a base64-encoded string.
+ *
+ * <b>Warning:</b> The paging state should not be exposed to or come from
+ * untrusted environments. The paging state could be spoofed and potentially
+ * used to gain access to other data.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] paging_state Base64-encoded paging state
+ * @param[in] paging_state_size
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the paging
+ * state is not a valid base64 string, otherwise an error occurred.
+ *
+ * @see cass_result_paging_state_token_base64()
+ */
+CASS_EXPORT CassError
+cass_statement_set_paging_state_token_base64(CassStatement* statement,
+                                             const char* paging_state,
+                                             size_t paging_state_size);
+
 /**
  * Sets the statement's timestamp.
  *
@@ -5393,6 +5575,50 @@ cass_statement_set_execution_profile_n(CassStatement* statement,
                                        const char* name,
                                        size_t name_length);
 
+/**
+ * Same as cass_statement_set_execution_profile(), but the profile is
+ * validated right away against the profiles of the connected session,
+ * instead of when the statement is executed.
+ *
+ * <b>Note:</b> This function waits while the session is being connected or
+ * closed, so it must not be called from a future callback.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] session
+ * @param[in] name Name of the execution profile to use with the statement
+ * @return CASS_OK if successful, CASS_ERROR_LIB_EXECUTION_PROFILE_INVALID
+ * if the profile is not registered in the session, CASS_ERROR_LIB_NO_HOSTS_AVAILABLE
+ * if the session is not connected, otherwise an error occurred.
+ *
+ * @see cass_statement_set_execution_profile()
+ */
+CASS_EXPORT CassError
+cass_statement_set_execution_profile_checked(CassStatement* statement,
+                                             const CassSession* session,
+                                             const char* name);
+
+/**
+ * Same as cass_statement_set_execution_profile_checked(), but with lengths
+ * for string parameters.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] session
+ * @param[in] name
+ * @param[in] name_length
+ * @return same as cass_statement_set_execution_profile_checked()
+ *
+ * @see cass_statement_set_execution_profile_checked()
+ */
+CASS_EXPORT CassError
+cass_statement_set_execution_profile_checked_n(CassStatement* statement,
+                                               const CassSession* session,
+                                               const char* name,
+                                               size_t name_length);
+
 /**
  * Sets whether the statement should use tracing.
  *
@@ -5408,6 +5634,24 @@ CASS_EXPORT CassError
 cass_statement_set_tracing(CassStatement* statement,
                            cass_bool_t enabled);
 
+/**
+ * Sets whether the rows of the statement's result should be built lazily.
+ * When enabled, the values of a row are converted only when the row is
+ * accessed for the first time, which reduces the cost of reading a few
+ * rows of a large page. The values returned are the same in both modes.
+ *
+ * <b>Default:</b> cass_false (all rows are built when the result arrives)
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] enabled
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_statement_set_lazy_rows(CassStatement* statement,
+                             cass_bool_t enabled);
+
 /**
  * Sets a specific host that should run the query.
  *
@@ -5642,6 +5886,10 @@ cass_statement_bind_int16_by_name_n(CassStatement* statement,
 /**
  * Binds an "int" to a query or bound statement at the specified index.
  *
+ * <b>Note:</b> For bound statements, a value bound to a "tinyint" or "smallint"
+ * column is narrowed to the type of the column. Values out of the range of the
+ * column return CASS_ERROR_LIB_INVALID_VALUE_TYPE.
+ *
  * @public @memberof CassStatement
  *
  * @param[in] statement
@@ -9412,6 +9660,32 @@ cass_result_free(const CassResult* result);
 CASS_EXPORT size_t
 cass_result_row_count(const CassResult* result);
 
+/**
+ * Returns true if the result is a rows result (e.g. a result of a SELECT
+ * statement), even if it contains no rows. Results of other statements
+ * (e.g. INSERT) are not rows results.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @return true if the result is a rows result, otherwise false.
+ */
+CASS_EXPORT cass_bool_t
+cass_result_has_rows(const CassResult* result);
+
+/**
+ * Returns true if the conditional statement (e.g. INSERT ... IF NOT EXISTS)
+ * was applied, based on the "[applied]" column of the first row. Results
+ * of non-conditional statements are always applied.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @return true if the statement was applied, otherwise false.
+ */
+CASS_EXPORT cass_bool_t
+cass_result_was_applied(const CassResult* result);
+
 /**
  * Gets the number of columns per row for the specified result.
  *
@@ -9440,6 +9714,40 @@ cass_result_column_name(const CassResult *result,
                         const char** name,
                         size_t* name_length);
 
+/**
+ * Gets the name of the keyspace of the column at index for the specified result.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] keyspace The keyspace name of the column at the specified index.
+ * @param[out] keyspace_length
+ * @return CASS_OK if successful, otherwise error occurred
+ */
+CASS_EXPORT CassError
+cass_result_column_keyspace(const CassResult *result,
+                            size_t index,
+                            const char** keyspace,
+                            size_t* keyspace_length);
+
+/**
+ * Gets the name of the table of the column at index for the specified result.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] table The table name of the column at the specified index.
+ * @param[out] table_length
+ * @return CASS_OK if successful, otherwise error occurred
+ */
+CASS_EXPORT CassError
+cass_result_column_table(const CassResult *result,
+                         size_t index,
+                         const char** table,
+                         size_t* table_length);
+
 /**
  * Gets the column type at index for the specified result.
  *
@@ -9516,6 +9824,29 @@ cass_result_paging_state_token(const CassResult* result,
                                const char** paging_state,
                                size_t* paging_state_size);
 
+/**
+ * Same as cass_result_paging_state_token(), but the paging state is returned
+ * as a base64-encoded string, e.g. to be persisted as text. The string is not
+ * null-terminated and is bound to the lifetime of the result object.
+ *
+ * <b>Warning:</b> The paging state should not be exposed to or come from
+ * untrusted environments. The paging state could be spoofed and potentially
+ * used to gain access to other data.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[out] paging_state
+ * @param[out] paging_state_size
+ * @return CASS_OK if successful, otherwise error occurred
+ *
+ * @see cass_statement_set_paging_state_token_base64()
+ */
+CASS_EXPORT CassError
+cass_result_paging_state_token_base64(const CassResult* result,
+                                      const char** paging_state,
+                                      size_t* paging_state_size);
+
 /***********************************************************************************
  *
  * Error result
@@ -10573,6 +10904,25 @@ CASS_EXPORT CassError
 cass_value_get_int64(const CassValue* value,
                      cass_int64_t* output);
 
+/**
+ * Gets the hours, minutes, seconds and nanoseconds of a time value.
+ *
+ * @public @memberof CassValue
+ *
+ * @param[in] value
+ * @param[out] hours
+ * @param[out] minutes
+ * @param[out] seconds
+ * @param[out] nanos
+ * @return CASS_OK if successful, otherwise error occurred
+ */
+CASS_EXPORT CassError
+cass_value_get_time_components(const CassValue* value,
+                               cass_int32_t* hours,
+                               cass_int32_t* minutes,
+                               cass_int32_t* seconds,
+                               cass_int32_t* nanos);
+
 /**
  * Gets a float for the specified value.
  *
@@ -10668,6 +11018,21 @@ cass_value_get_bytes(const CassValue* value,
                      const cass_byte_t** output,
                      size_t* output_size);
 
+/**
+ * Gets the size of the bytes returned by cass_value_get_bytes() for
+ * the specified value.
+ *
+ * @public @memberof CassValue
+ *
+ * @param[in] value
+ * @return The size of the bytes, or 0 if the value is null or its bytes
+ * cannot be retrieved.
+ *
+ * @see cass_value_get_bytes()
+ */
+CASS_EXPORT size_t
+cass_value_bytes_size(const CassValue* value);
+
 /**
  * Gets a decimal for the specified value.
  *
@@ -10726,6 +11091,25 @@ cass_value_type(const CassValue* value);
 CASS_EXPORT cass_bool_t
 cass_value_is_null(const CassValue* value);
 
+/**
+ * Returns true if the specified values are equal. Values of different
+ * types are never equal. Null values are equal only to other null values.
+ * Elements of sets and maps are compared regardless of their order.
+ * Decimals are equal only if both their unscaled values and scales are
+ * equal (e.g. 1.0 is not equal to 1.00), and durations are equal only if
+ * their months, days and nanoseconds are equal (e.g. 1 day is not equal to
+ * 24 hours).
+ *
+ * @public @memberof CassValue
+ *
+ * @param[in] value_a
+ * @param[in] value_b
+ * @return true if the values are equal, otherwise false.
+ */
+CASS_EXPORT cass_bool_t
+cass_value_equals(const CassValue* value_a,
+                  const CassValue* value_b);
+
 /**
  * Returns true if a specified value is a collection.
  *
@@ -125,4 +125,19 @@ fn main() {
         &["CassCompressionType_", "CassCompressionType"],
         &out_path,
     );
+    prepare_cppdriver_data(
+        "cppdriver_metrics_types.rs",
+        &["CassMetrics_", "CassMetrics"],
+        &out_path,
+    );
+    prepare_cppdriver_data(
+        "cppdriver_host_listener.rs",
+        &["CassHostListenerEvent_", "CassHostListenerEvent"],
+        &out_path,
+    );
+    prepare_cppdriver_data(
+        "cppdriver_iterator_types.rs",
+        &["CassIteratorType_", "CassIteratorType"],
+        &out_path,
+    );
 }
@@ -1,4 +1,4 @@
-use crate::argconv::{free_boxed, ptr_to_ref, ptr_to_ref_mut};
+use crate::argconv::{free_boxed, ptr_to_cstr_n, ptr_to_ref, ptr_to_ref_mut, strlen};
 use crate::cass_error::CassError;
 use crate::cass_types::CassConsistency;
 use crate::cass_types::{make_batch_type, CassBatchType};
@@ -6,13 +6,20 @@ use crate::statement::{CassStatement, Statement};
 use crate::types::*;
 use scylla::batch::Batch;
 use scylla::frame::response::result::CqlValue;
+use scylla::frame::types::{Consistency, SerialConsistency};
 use scylla::frame::value::MaybeUnset;
 use std::convert::TryInto;
+use std::os::raw::c_char;
 use std::sync::Arc;
 
 pub struct CassBatch {
     pub state: Arc<CassBatchState>,
     pub batch_request_timeout_ms: Option<cass_uint64_t>,
+    pub exec_profile: Option<String>,
+    // Consistencies set explicitly for the batch. They take precedence
+    // over the ones of the execution profile.
+    pub consistency: Option<Consistency>,
+    pub serial_consistency: Option<SerialConsistency>,
 }
 
 #[derive(Clone)]
@@ -24,12 +31,20 @@ pub struct CassBatchState {
 #[no_mangle]
 pub unsafe extern "C" fn cass_batch_new(type_: CassBatchType) -> *mut CassBatch {
     if let Some(batch_type) = make_batch_type(type_) {
+        let mut batch = Batch::new(batch_type);
+
+        // Set Cpp Driver default configuration for batches (the same as for queries):
+        batch.set_consistency(Consistency::One);
+
         Box::into_raw(Box::new(CassBatch {
             state: Arc::new(CassBatchState {
-                batch: Batch::new(batch_type),
+                batch,
                 bound_values: Vec::new(),
             }),
             batch_request_timeout_ms: None,
+            exec_profile: None,
+            consistency: None,
+            serial_consistency: None,
         }))
     } else {
         std::ptr::null_mut()
@@ -51,9 +66,7 @@ pub unsafe extern "C" fn cass_batch_set_consistency(
         Some(c) => c,
         None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
     };
-    Arc::make_mut(&mut batch.state)
-        .batch
-        .set_consistency(consistency);
+    batch.consistency = Some(consistency);
 
     CassError::CASS_OK
 }
@@ -68,9 +81,7 @@ pub unsafe extern "C" fn cass_batch_set_serial_consistency(
         Some(c) => c,
         None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
     };
-    Arc::make_mut(&mut batch.state)
-        .batch
-        .set_serial_consistency(Some(serial_consistency));
+    batch.serial_consistency = Some(serial_consistency);
 
     CassError::CASS_OK
 }
@@ -100,6 +111,37 @@ pub unsafe extern "C" fn cass_batch_set_request_timeout(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_batch_set_execution_profile(
+    batch: *mut CassBatch,
+    name: *const c_char,
+) -> CassError {
+    cass_batch_set_execution_profile_n(batch, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_batch_set_execution_profile_n(
+    batch: *mut CassBatch,
+    name: *const c_char,
+    name_length: size_t,
+) -> CassError {
+    let batch = ptr_to_ref_mut(batch);
+
+    // NULL or empty name clears the execution profile of the batch.
+    if name.is_null() || name_length == 0 {
+        batch.exec_profile = None;
+        return CassError::CASS_OK;
+    }
+
+    match ptr_to_cstr_n(name, name_length) {
+        Some(name) => {
+            batch.exec_profile = Some(name.to_string());
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_batch_set_is_idempotent(
     batch: *mut CassBatch,
@@ -135,6 +177,9 @@ pub unsafe extern "C" fn cass_batch_add_statement(
     let state = Arc::make_mut(&mut batch.state);
     let statement = ptr_to_ref(statement);
 
+    // Consistency of the statement is not carried into the batch -
+    // a batch is executed with a single, batch-level consistency
+    // (set with cass_batch_set_consistency), as in Cpp Driver.
     match &statement.statement {
         Statement::Simple(q) => state.batch.append_statement(q.query.clone()),
         Statement::Prepared(p) => state.batch.append_statement((**p).clone()),
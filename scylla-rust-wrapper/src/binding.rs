@@ -47,12 +47,94 @@
 //!     It can be used for binding named parameter in CassStatement or field by name in CassUserType.
 //!  * Functions from make_appender don't take any extra argument, as they are for use by CassCollection
 //!     functions - values are appended to collection.
-use crate::cass_types::CassDataType;
+use crate::cass_types::{CassDataType, CassDataTypeArc};
 use scylla::frame::response::result::CqlValue;
 
-pub fn is_compatible_type(_data_type: &CassDataType, _value: &Option<CqlValue>) -> bool {
-    // TODO: cppdriver actually checks types.
-    true
+pub fn is_compatible_type(data_type: &CassDataType, value: &Option<CqlValue>) -> bool {
+    match (data_type, value) {
+        // Null is compatible with every type.
+        (_, None) => true,
+        (CassDataType::Tuple(field_types), Some(CqlValue::Tuple(fields))) => {
+            // Tuple type without specified field types is compatible with every tuple.
+            field_types.is_empty()
+                || field_types.len() == fields.len()
+                    && field_types
+                        .iter()
+                        .zip(fields)
+                        .all(|(field_type, field)| is_compatible_type(field_type, field))
+        }
+        (
+            CassDataType::UDT(udt_type),
+            Some(CqlValue::UserDefinedType {
+                keyspace,
+                type_name,
+                fields,
+            }),
+        ) => {
+            (udt_type.keyspace.is_empty() || udt_type.keyspace == *keyspace)
+                && (udt_type.name.is_empty() || udt_type.name == *type_name)
+                && udt_type.field_types.len() == fields.len()
+                && udt_type.field_types.iter().zip(fields).all(
+                    |((field_type_name, field_type), (field_name, field))| {
+                        field_type_name == field_name && is_compatible_type(field_type, field)
+                    },
+                )
+        }
+        (CassDataType::Tuple(_), Some(_)) | (CassDataType::UDT(_), Some(_)) => false,
+        // TODO: cppdriver actually checks types of the other values too.
+        _ => true,
+    }
+}
+
+// Checks whether a value declared with type `actual` may be bound where `expected` is declared.
+// Types without specified subtypes (e.g. created with `cass_tuple_new`) are compatible with
+// every type of the same kind.
+pub fn is_compatible_data_type(expected: &CassDataType, actual: &CassDataType) -> bool {
+    fn are_compatible_subtypes(
+        expected: &Option<CassDataTypeArc>,
+        actual: &Option<CassDataTypeArc>,
+    ) -> bool {
+        match (expected, actual) {
+            (Some(expected), Some(actual)) => is_compatible_data_type(expected, actual),
+            _ => true,
+        }
+    }
+
+    match (expected, actual) {
+        (CassDataType::Value(expected), CassDataType::Value(actual)) => expected == actual,
+        (CassDataType::Custom(expected), CassDataType::Custom(actual)) => expected == actual,
+        (CassDataType::List(expected), CassDataType::List(actual))
+        | (CassDataType::Set(expected), CassDataType::Set(actual)) => {
+            are_compatible_subtypes(expected, actual)
+        }
+        (
+            CassDataType::Map(expected_key, expected_value),
+            CassDataType::Map(actual_key, actual_value),
+        ) => {
+            are_compatible_subtypes(expected_key, actual_key)
+                && are_compatible_subtypes(expected_value, actual_value)
+        }
+        (CassDataType::Tuple(expected), CassDataType::Tuple(actual)) => {
+            expected.is_empty()
+                || actual.is_empty()
+                || expected.len() == actual.len()
+                    && expected
+                        .iter()
+                        .zip(actual)
+                        .all(|(expected, actual)| is_compatible_data_type(expected, actual))
+        }
+        (CassDataType::UDT(expected), CassDataType::UDT(actual)) => {
+            (expected.keyspace.is_empty() || expected.keyspace == actual.keyspace)
+                && (expected.name.is_empty() || expected.name == actual.name)
+                && expected.field_types.len() == actual.field_types.len()
+                && expected.field_types.iter().zip(&actual.field_types).all(
+                    |((expected_name, expected), (actual_name, actual))| {
+                        expected_name == actual_name && is_compatible_data_type(expected, actual)
+                    },
+                )
+        }
+        _ => false,
+    }
 }
 
 macro_rules! make_index_binder {
@@ -191,6 +273,8 @@ macro_rules! invoke_binder_maker_macro_with_type {
             [v @ cass_int64_t]
         );
     };
+    // Floating point values are passed as they are, so NaN and infinities
+    // are serialized with their exact bit patterns.
     (float, $macro_name:ident, $this:ty, $consume_v:expr, $fn:ident) => {
         $macro_name!(
             $this,
@@ -242,7 +326,12 @@ macro_rules! invoke_binder_maker_macro_with_type {
             $consume_v,
             $fn,
             |v, v_size| {
-                let v_vec = std::slice::from_raw_parts(v, v_size as usize).to_vec();
+                // Empty value may be passed as a null pointer.
+                let v_vec = if v_size == 0 {
+                    Vec::new()
+                } else {
+                    std::slice::from_raw_parts(v, v_size as usize).to_vec()
+                };
                 Ok(Some(Blob(v_vec)))
             },
             [v @ *const cass_byte_t, v_size @ size_t]
@@ -281,6 +370,10 @@ macro_rules! invoke_binder_maker_macro_with_type {
             $consume_v,
             $fn,
             |p: *const crate::collection::CassCollection| {
+                // Null collection is bound as an explicit null (unlike an empty collection).
+                if p.is_null() {
+                    return Ok(None);
+                }
                 match std::convert::TryInto::try_into(ptr_to_ref(p)) {
                     Ok(v) => Ok(Some(v)),
                     Err(_) => Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE),
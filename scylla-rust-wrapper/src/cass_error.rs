@@ -42,7 +42,8 @@ impl From<&DbError> for CassError {
             DbError::Other(num) => {
                 CassError((CassErrorSource::CASS_ERROR_SOURCE_SERVER.0 << 24) | *num as u32)
             }
-            // TODO: add appropriate error if rate limit reached
+            // Rate limit errors are Scylla-specific and Cpp Driver has no code for them,
+            // so the closest one is used - the request was rejected without being executed.
             DbError::RateLimitReached { .. } => CassError::CASS_ERROR_SERVER_UNAVAILABLE,
         }
     }
@@ -69,14 +70,16 @@ impl From<&NewSessionError> for CassError {
                 CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE
             }
             NewSessionError::EmptyKnownNodesList => CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-            NewSessionError::DbError(_db_error, _string) => CassError::CASS_ERROR_LAST_ENTRY,
+            // Server errors (e.g. bad credentials) are reported the same way as for requests.
+            NewSessionError::DbError(db_error, _string) => CassError::from(db_error),
             NewSessionError::BadQuery(_bad_query) => CassError::CASS_ERROR_LAST_ENTRY,
-            NewSessionError::IoError(_io_error) => CassError::CASS_ERROR_LAST_ENTRY,
+            // Connection errors mean that none of the contact points could be reached.
+            NewSessionError::IoError(_io_error) => CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
             NewSessionError::ProtocolError(_str) => {
                 CassError::CASS_ERROR_LIB_UNABLE_TO_DETERMINE_PROTOCOL
             }
             NewSessionError::InvalidMessage(_string) => CassError::CASS_ERROR_LAST_ENTRY,
-            NewSessionError::TimeoutError => CassError::CASS_ERROR_LAST_ENTRY,
+            NewSessionError::TimeoutError => CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
             NewSessionError::TooManyOrphanedStreamIds(_) => CassError::CASS_ERROR_LAST_ENTRY,
             NewSessionError::UnableToAllocStreamId => CassError::CASS_ERROR_LAST_ENTRY,
             NewSessionError::RequestTimeout(_) => CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
@@ -1,13 +1,20 @@
 use crate::argconv::*;
 use crate::cass_error::CassError;
-use crate::future::CassFuture;
+use crate::cass_types::CassConsistency;
+use crate::exec_profile::CassExecProfile;
+use crate::host_listener::{CassHostListenerCallback, HostListener};
 use crate::retry_policy::CassRetryPolicy;
 use crate::retry_policy::RetryPolicy::*;
 use crate::ssl::CassSsl;
 use crate::types::*;
+use crate::uuid::CassUuid;
 use core::time::Duration;
 use openssl::ssl::SslContextBuilder;
 use openssl_sys::SSL_CTX_up_ref;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use scylla::frame::types::{Consistency, SerialConsistency};
 use scylla::frame::Compression;
 use scylla::load_balancing::{
     DcAwareRoundRobinPolicy, LoadBalancingPolicy, RoundRobinPolicy, TokenAwarePolicy,
@@ -15,8 +22,11 @@ use scylla::load_balancing::{
 use scylla::retry_policy::RetryPolicy;
 use scylla::speculative_execution::SimpleSpeculativeExecutionPolicy;
 use scylla::SessionBuilder;
-use std::os::raw::{c_char, c_int, c_uint};
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::os::raw::{c_char, c_int, c_uint, c_void};
 use std::sync::Arc;
+use tracing::warn;
 
 include!(concat!(env!("OUT_DIR"), "/cppdriver_compression_types.rs"));
 
@@ -25,7 +35,9 @@ enum CassClusterChildLoadBalancingPolicy {
     RoundRobinPolicy,
     DcAwareRoundRobinPolicy {
         local_dc: String,
-        include_remote_nodes: bool,
+        // Whether requests may fail over to nodes of remote DCs. Set to false,
+        // requests are pinned strictly to the local DC.
+        permit_dc_failover: bool,
     },
 }
 
@@ -35,21 +47,171 @@ pub struct CassCluster {
 
     contact_points: Vec<String>,
     port: u16,
+    use_randomized_contact_points: bool,
+    // Seed of shuffling the contact points. It is set only by the tests,
+    // which need the order to be reproducible.
+    contact_point_shuffle_seed: Option<u64>,
+    connection_idle_timeout: Duration,
+    // Deadline of the whole session connect, unlike the connect timeout which bounds
+    // only establishing a single connection.
+    session_connect_deadline: Option<Duration>,
+    // Default timeout of requests, used if neither the statement nor its profile sets one.
+    request_timeout_ms: Option<cass_uint64_t>,
+    // Default consistencies, used if neither the statement nor its profile sets them.
+    // Without them, the defaults of statements are used.
+    consistency: Option<Consistency>,
+    serial_consistency: Option<SerialConsistency>,
+    // Maximum number of requests of a session in flight at once. Requests beyond it wait.
+    max_concurrent_requests: Option<u32>,
+    // Range of local ports [lo, hi) to be used by shard-aware connections.
+    local_port_range: Option<(u16, u16)>,
+    // Time to wait for new requests to coalesce into a single write.
+    coalesce_delay: Option<Duration>,
 
     child_load_balancing_policy: CassClusterChildLoadBalancingPolicy,
     token_aware_policy_enabled: bool,
+    token_aware_shuffling_replicas_enabled: bool,
     use_beta_protocol_version: bool,
+    client_id: Option<CassUuid>,
+    histogram_refresh_interval: Option<Duration>,
+    host_listener: Option<HostListener>,
+
+    execution_profile_map: HashMap<String, CassExecProfile>,
 }
 
-pub struct CassCustomPayload;
+impl CassCluster {
+    pub fn execution_profile_map(&self) -> &HashMap<String, CassExecProfile> {
+        &self.execution_profile_map
+    }
+
+    pub fn client_id(&self) -> Option<CassUuid> {
+        self.client_id
+    }
+
+    pub fn histogram_refresh_interval(&self) -> Option<Duration> {
+        self.histogram_refresh_interval
+    }
+
+    pub fn host_listener(&self) -> Option<HostListener> {
+        self.host_listener
+    }
+
+    pub fn session_connect_deadline(&self) -> Option<Duration> {
+        self.session_connect_deadline
+    }
+
+    pub fn request_timeout_ms(&self) -> Option<cass_uint64_t> {
+        self.request_timeout_ms
+    }
+
+    pub fn consistency(&self) -> Option<Consistency> {
+        self.consistency
+    }
+
+    pub fn serial_consistency(&self) -> Option<SerialConsistency> {
+        self.serial_consistency
+    }
+
+    pub fn max_concurrent_requests(&self) -> Option<u32> {
+        self.max_concurrent_requests
+    }
+
+    // Shuffling of replicas is applicable only when token-aware routing is enabled.
+    pub fn token_aware_shuffling_replicas_enabled(&self) -> bool {
+        self.token_aware_policy_enabled && self.token_aware_shuffling_replicas_enabled
+    }
+
+    pub fn connection_heartbeat_interval(&self) -> Option<Duration> {
+        self.session_builder.config.keepalive_interval
+    }
+
+    pub fn connection_idle_timeout(&self) -> Duration {
+        self.connection_idle_timeout
+    }
+
+    // Returns None when the cluster does not use the DC-aware policy.
+    pub fn dc_aware_permit_dc_failover(&self) -> Option<bool> {
+        match &self.child_load_balancing_policy {
+            CassClusterChildLoadBalancingPolicy::DcAwareRoundRobinPolicy {
+                permit_dc_failover,
+                ..
+            } => Some(*permit_dc_failover),
+            CassClusterChildLoadBalancingPolicy::RoundRobinPolicy => None,
+        }
+    }
+
+    pub fn coalesce_delay(&self) -> Option<Duration> {
+        self.coalesce_delay
+    }
+
+    pub fn compression(&self) -> Option<Compression> {
+        self.session_builder.config.compression
+    }
+
+    pub fn local_port_range(&self) -> Option<(u16, u16)> {
+        self.local_port_range
+    }
+
+    pub fn set_contact_point_shuffle_seed(&mut self, seed: u64) {
+        self.contact_point_shuffle_seed = Some(seed);
+    }
+
+    // Contact points, in the order in which they are passed to the session.
+    pub fn known_nodes(&self) -> Vec<String> {
+        let mut known_nodes: Vec<_> = self
+            .contact_points
+            .iter()
+            .map(|cp| format!("{}:{}", cp, self.port))
+            .collect();
+
+        // Contact points are shuffled, so the load of initial connections
+        // is spread among them when many clients connect at once.
+        if self.use_randomized_contact_points {
+            match self.contact_point_shuffle_seed {
+                Some(seed) => known_nodes.shuffle(&mut StdRng::seed_from_u64(seed)),
+                None => known_nodes.shuffle(&mut rand::thread_rng()),
+            }
+        }
+
+        known_nodes
+    }
+}
 
 pub fn build_session_builder(cluster: &CassCluster) -> SessionBuilder {
-    let known_nodes: Vec<_> = cluster
-        .contact_points
-        .clone()
-        .into_iter()
-        .map(|cp| format!("{}:{}", cp, cluster.port))
-        .collect();
+    let known_nodes = cluster.known_nodes();
+
+    if cluster.token_aware_policy_enabled && !cluster.token_aware_shuffling_replicas_enabled() {
+        // FIXME: Rust Driver's TokenAwarePolicy does not allow to configure shuffling of replicas.
+        warn!("Disabling shuffling of replicas in token-aware routing is not supported");
+    }
+
+    if let Some(keepalive_interval) = cluster.session_builder.config.keepalive_interval {
+        // Idle timeout equal to 0 disables it.
+        let idle_timeout = cluster.connection_idle_timeout;
+        if !idle_timeout.is_zero() && keepalive_interval >= idle_timeout {
+            warn!(
+                "Heartbeat interval ({:?}) is not shorter than connection idle timeout ({:?}) - \
+                 connections may be considered idle before a heartbeat is sent",
+                keepalive_interval, idle_timeout
+            );
+        }
+    }
+
+    if let Some((lo, hi)) = cluster.local_port_range {
+        // FIXME: Rust Driver does not allow to configure local ports of shard-aware connections.
+        warn!(
+            "Local port range [{}, {}) is not supported - ports are chosen by the driver",
+            lo, hi
+        );
+    }
+
+    if let Some(delay) = cluster.coalesce_delay {
+        // FIXME: Rust Driver does not allow to configure coalescing of writes.
+        warn!(
+            "Coalesce delay ({}us) is not supported - ignoring it",
+            delay.as_micros()
+        );
+    }
 
     let load_balancing: Arc<dyn LoadBalancingPolicy> =
         match cluster.child_load_balancing_policy.clone() {
@@ -62,10 +224,10 @@ pub fn build_session_builder(cluster: &CassCluster) -> SessionBuilder {
             }
             CassClusterChildLoadBalancingPolicy::DcAwareRoundRobinPolicy {
                 local_dc,
-                include_remote_nodes,
+                permit_dc_failover,
             } => {
                 let mut dc_aware_policy = DcAwareRoundRobinPolicy::new(local_dc);
-                dc_aware_policy.set_include_remote_nodes(include_remote_nodes);
+                dc_aware_policy.set_include_remote_nodes(permit_dc_failover);
 
                 if cluster.token_aware_policy_enabled {
                     Arc::new(TokenAwarePolicy::new(Box::new(dc_aware_policy)))
@@ -86,17 +248,40 @@ pub fn build_session_builder(cluster: &CassCluster) -> SessionBuilder {
 pub unsafe extern "C" fn cass_cluster_new() -> *mut CassCluster {
     Box::into_raw(Box::new(CassCluster {
         session_builder: SessionBuilder::new()
-            .retry_policy(Box::new(scylla::retry_policy::DefaultRetryPolicy)),
+            .retry_policy(Box::new(scylla::retry_policy::DefaultRetryPolicy))
+            // Cpp Driver sends heartbeats every 30 seconds by default.
+            .keepalive_interval(Duration::from_secs(30)),
         port: 9042,
         contact_points: Vec::new(),
+        // Cpp Driver randomizes contact points by default.
+        use_randomized_contact_points: true,
+        contact_point_shuffle_seed: None,
+        // Cpp Driver terminates connections after 60 seconds without a heartbeat response.
+        connection_idle_timeout: Duration::from_secs(60),
+        session_connect_deadline: None,
+        // Cpp Driver's default request timeout is 12 seconds.
+        request_timeout_ms: Some(12_000),
+        consistency: None,
+        serial_consistency: None,
+        // Unlike in Cpp Driver, where the threshold is deprecated and does nothing,
+        // the number of concurrent requests is limited only if configured.
+        max_concurrent_requests: None,
+        local_port_range: None,
+        coalesce_delay: None,
         // Per DataStax documentation: Without additional configuration the C/C++ driver
         // defaults to using Datacenter-aware load balancing with token-aware routing.
         child_load_balancing_policy: CassClusterChildLoadBalancingPolicy::DcAwareRoundRobinPolicy {
             local_dc: "".to_string(),
-            include_remote_nodes: true,
+            permit_dc_failover: true,
         },
         token_aware_policy_enabled: true,
+        // Cpp Driver enables shuffling of replicas by default (since version 2.11.0).
+        token_aware_shuffling_replicas_enabled: true,
         use_beta_protocol_version: false,
+        client_id: None,
+        histogram_refresh_interval: None,
+        host_listener: None,
+        execution_profile_map: HashMap::new(),
     }))
 }
 
@@ -155,10 +340,11 @@ unsafe fn cluster_set_contact_points(
 
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_use_randomized_contact_points(
-    _cluster_raw: *mut CassCluster,
-    _enabled: cass_bool_t,
+    cluster_raw: *mut CassCluster,
+    enabled: cass_bool_t,
 ) -> CassError {
-    // FIXME: should set `use_randomized_contact_points` flag in cluster config
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.use_randomized_contact_points = enabled != 0;
 
     CassError::CASS_OK
 }
@@ -190,6 +376,105 @@ pub unsafe extern "C" fn cass_cluster_set_connect_timeout(
     cluster.session_builder.config.connect_timeout = Duration::from_millis(timeout_ms.into());
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_session_connect_deadline(
+    cluster_raw: *mut CassCluster,
+    deadline_ms: c_uint,
+) {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    // Deadline equal to 0 disables the deadline.
+    cluster.session_connect_deadline = if deadline_ms > 0 {
+        Some(Duration::from_millis(deadline_ms.into()))
+    } else {
+        None
+    };
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_consistency(
+    cluster_raw: *mut CassCluster,
+    consistency: CassConsistency,
+) -> CassError {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    let consistency = match consistency.try_into().ok() {
+        Some(c) => c,
+        None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+    cluster.consistency = Some(consistency);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_serial_consistency(
+    cluster_raw: *mut CassCluster,
+    serial_consistency: CassConsistency,
+) -> CassError {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    let serial_consistency = match serial_consistency.try_into().ok() {
+        Some(c) => c,
+        None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+    cluster.serial_consistency = Some(serial_consistency);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_request_timeout(
+    cluster_raw: *mut CassCluster,
+    timeout_ms: c_uint,
+) {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    // Timeout equal to 0 disables the timeout.
+    cluster.request_timeout_ms = if timeout_ms > 0 {
+        Some(timeout_ms.into())
+    } else {
+        None
+    };
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_max_concurrent_requests_threshold(
+    cluster_raw: *mut CassCluster,
+    num_requests: c_uint,
+) -> CassError {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    // Threshold equal to 0 disables the limit.
+    cluster.max_concurrent_requests = if num_requests > 0 {
+        Some(num_requests)
+    } else {
+        None
+    };
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_connection_heartbeat_interval(
+    cluster_raw: *mut CassCluster,
+    interval_secs: c_uint,
+) {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    // Interval equal to 0 disables heartbeat messages.
+    cluster.session_builder.config.keepalive_interval = if interval_secs > 0 {
+        Some(Duration::from_secs(interval_secs.into()))
+    } else {
+        None
+    };
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_connection_idle_timeout(
+    cluster_raw: *mut CassCluster,
+    timeout_secs: c_uint,
+) {
+    // FIXME: Rust Driver does not terminate connections without a successful
+    // heartbeat response, so the timeout is only checked against the heartbeat interval.
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.connection_idle_timeout = Duration::from_secs(timeout_secs.into());
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_port(
     cluster_raw: *mut CassCluster,
@@ -204,6 +489,35 @@ pub unsafe extern "C" fn cass_cluster_set_port(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_local_port_range(
+    cluster_raw: *mut CassCluster,
+    lo: c_int,
+    hi: c_int,
+) -> CassError {
+    if lo < 1 || hi > 65535 || lo >= hi {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.local_port_range = Some((lo as u16, hi as u16));
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_coalesce_delay(
+    cluster_raw: *mut CassCluster,
+    delay_us: cass_int64_t,
+) -> CassError {
+    if delay_us < 0 {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.coalesce_delay = Some(Duration::from_micros(delay_us as u64));
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_credentials(
     cluster: *mut CassCluster,
@@ -236,6 +550,34 @@ pub unsafe extern "C" fn cass_cluster_set_credentials_n(
     cluster.session_builder.config.auth_password = Some(password.to_string());
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_dse_gssapi_authenticator(
+    cluster: *mut CassCluster,
+    service: *const c_char,
+    principal: *const c_char,
+) -> CassError {
+    cass_cluster_set_dse_gssapi_authenticator_n(
+        cluster,
+        service,
+        strlen(service),
+        principal,
+        strlen(principal),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_dse_gssapi_authenticator_n(
+    _cluster: *mut CassCluster,
+    _service: *const c_char,
+    _service_length: size_t,
+    _principal: *const c_char,
+    _principal_length: size_t,
+) -> CassError {
+    // FIXME: Rust Driver does not support GSSAPI authentication. The specific error
+    // is returned, so the application can detect it instead of failing to connect.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_load_balance_round_robin(cluster_raw: *mut CassCluster) {
     let cluster = ptr_to_ref_mut(cluster_raw);
@@ -271,8 +613,11 @@ pub unsafe extern "C" fn cass_cluster_set_load_balance_dc_aware_n(
     }
 
     if used_hosts_per_remote_dc != 0 {
-        // TODO: Add warning that the parameter is deprecated and not supported in the driver.
-        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+        // The parameter is deprecated in Cpp Driver, so it is accepted, but ignored.
+        warn!(
+            "used_hosts_per_remote_dc ({}) is deprecated and not supported - ignoring it",
+            used_hosts_per_remote_dc
+        );
     }
 
     let local_dc = ptr_to_cstr_n(local_dc_raw, local_dc_length)
@@ -283,12 +628,31 @@ pub unsafe extern "C" fn cass_cluster_set_load_balance_dc_aware_n(
     cluster.child_load_balancing_policy =
         CassClusterChildLoadBalancingPolicy::DcAwareRoundRobinPolicy {
             local_dc,
-            include_remote_nodes: allow_remote_dcs_for_local_cl != 0,
+            permit_dc_failover: allow_remote_dcs_for_local_cl != 0,
         };
 
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_load_balance_dc_aware_permit_dc_failover(
+    cluster_raw: *mut CassCluster,
+    enabled: cass_bool_t,
+) -> CassError {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    match &mut cluster.child_load_balancing_policy {
+        CassClusterChildLoadBalancingPolicy::DcAwareRoundRobinPolicy {
+            permit_dc_failover, ..
+        } => {
+            *permit_dc_failover = enabled != 0;
+            CassError::CASS_OK
+        }
+        CassClusterChildLoadBalancingPolicy::RoundRobinPolicy => {
+            CassError::CASS_ERROR_LIB_BAD_PARAMS
+        }
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_cloud_secure_connection_bundle_n(
     _cluster_raw: *mut CassCluster,
@@ -334,29 +698,6 @@ pub unsafe extern "C" fn cass_cluster_set_exponential_reconnect(
     CassError::CASS_OK
 }
 
-#[no_mangle]
-pub extern "C" fn cass_custom_payload_new() -> *const CassCustomPayload {
-    // FIXME: should create a new custom payload that must be freed
-    std::ptr::null()
-}
-
-#[no_mangle]
-pub extern "C" fn cass_future_custom_payload_item(
-    _future: *mut CassFuture,
-    _i: size_t,
-    _name: *const c_char,
-    _name_length: size_t,
-    _value: *const cass_byte_t,
-    _value_size: size_t,
-) -> CassError {
-    CassError::CASS_OK
-}
-
-#[no_mangle]
-pub extern "C" fn cass_future_custom_payload_item_count(_future: *mut CassFuture) -> size_t {
-    0
-}
-
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_use_beta_protocol_version(
     cluster_raw: *mut CassCluster,
@@ -424,15 +765,59 @@ pub unsafe extern "C" fn cass_cluster_set_no_speculative_execution_policy(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_execution_profile(
+    cluster: *mut CassCluster,
+    name: *const c_char,
+    profile: *const CassExecProfile,
+) -> CassError {
+    cass_cluster_set_execution_profile_n(cluster, name, strlen(name), profile)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_execution_profile_n(
+    cluster_raw: *mut CassCluster,
+    name: *const c_char,
+    name_length: size_t,
+    profile: *const CassExecProfile,
+) -> CassError {
+    if name.is_null() || name_length == 0 || profile.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    let name = match ptr_to_cstr_n(name, name_length) {
+        Some(name) => name.to_string(),
+        None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    // The profile is copied, so further changes to it do not affect the cluster.
+    let profile = ptr_to_ref(profile).clone();
+    cluster.execution_profile_map.insert(name, profile);
+
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_token_aware_routing(
     cluster_raw: *mut CassCluster,
     enabled: cass_bool_t,
 ) {
     let cluster = ptr_to_ref_mut(cluster_raw);
+    // Only prepared statements are routed token-aware, as only they carry the information
+    // about partition key. Simple statements are routed by the child policy.
     cluster.token_aware_policy_enabled = enabled != 0;
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_token_aware_routing_shuffle_replicas(
+    cluster_raw: *mut CassCluster,
+    enabled: cass_bool_t,
+) {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.token_aware_shuffling_replicas_enabled = enabled != 0;
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_retry_policy(
     cluster_raw: *mut CassCluster,
@@ -455,8 +840,13 @@ pub unsafe extern "C" fn cass_cluster_set_ssl(cluster: *mut CassCluster, ssl: *m
     let cluster_from_raw = ptr_to_ref_mut(cluster);
     let cass_ssl = clone_arced(ssl);
 
+    // Verify mode of the context is set according to the verify flags of `ssl`.
+    cass_ssl.apply_verify_flags();
+
     let ssl_context_builder = SslContextBuilder::from_ptr(cass_ssl.ssl_context);
     // Reference count is increased as tokio_openssl will try to free `ssl_context` when calling `SSL_free`.
+    // This way the cluster holds its own reference, so `ssl` may be freed right after this call,
+    // and each session built from the cluster shares the context by cloning the reference.
     SSL_CTX_up_ref(cass_ssl.ssl_context);
 
     cluster_from_raw.session_builder.config.ssl_context = Some(ssl_context_builder.build());
@@ -471,8 +861,96 @@ pub unsafe extern "C" fn cass_cluster_set_compression(
     let compression = match compression_type {
         CassCompressionType::CASS_COMPRESSION_LZ4 => Some(Compression::Lz4),
         CassCompressionType::CASS_COMPRESSION_SNAPPY => Some(Compression::Snappy),
-        _ => None,
+        CassCompressionType::CASS_COMPRESSION_NONE => None,
+        // The function returns nothing, so unknown types are ignored,
+        // leaving the compression unchanged.
+        _ => {
+            warn!(
+                "Unknown compression type {} - leaving compression unchanged",
+                compression_type.0
+            );
+            return;
+        }
     };
 
     cluster_from_raw.session_builder.config.compression = compression;
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_application_name(
+    cluster: *mut CassCluster,
+    application_name: *const c_char,
+) {
+    cass_cluster_set_application_name_n(cluster, application_name, strlen(application_name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_application_name_n(
+    _cluster: *mut CassCluster,
+    application_name_raw: *const c_char,
+    application_name_length: size_t,
+) {
+    // FIXME: Rust Driver does not allow to set custom STARTUP options, so APPLICATION_NAME
+    // cannot be sent to the server. The setter returns no error code, so the name is
+    // not stored and the user is only warned.
+    match ptr_to_cstr_n(application_name_raw, application_name_length) {
+        Some(application_name) => warn!(
+            "Setting application name (\"{}\") is not supported - it will not be sent to the server",
+            application_name
+        ),
+        None => warn!("Setting application name is not supported"),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_no_compact(
+    _cluster_raw: *mut CassCluster,
+    enabled: cass_bool_t,
+) -> CassError {
+    // NO_COMPACT is a STARTUP option and the Rust driver does not allow
+    // to set custom STARTUP options. Disabling it is the default behaviour.
+    if enabled != 0 {
+        return CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED;
+    }
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_client_id(
+    cluster_raw: *mut CassCluster,
+    client_id: CassUuid,
+) {
+    // FIXME: Rust Driver does not allow to set custom STARTUP options,
+    // so the client id is not sent to the server.
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.client_id = Some(client_id);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_histogram_refresh_interval(
+    cluster_raw: *mut CassCluster,
+    refresh_interval: c_uint,
+) -> CassError {
+    if refresh_interval == 0 {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.histogram_refresh_interval = Some(Duration::from_millis(refresh_interval.into()));
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_host_listener_callback(
+    cluster_raw: *mut CassCluster,
+    callback: CassHostListenerCallback,
+    data: *mut c_void,
+) -> CassError {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    // Setting NULL callback removes the listener.
+    cluster.host_listener = HostListener::new(callback, data);
+
+    CassError::CASS_OK
+}
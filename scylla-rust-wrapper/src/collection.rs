@@ -1,24 +1,54 @@
 use crate::argconv::*;
+use crate::binding::is_compatible_type;
 use crate::cass_error::CassError;
+use crate::cass_types::{CassDataType, CassDataTypeArc};
 use crate::types::*;
 use scylla::frame::response::result::CqlValue;
 use scylla::frame::response::result::CqlValue::*;
 use std::convert::TryFrom;
+use std::sync::Arc;
 
 include!(concat!(env!("OUT_DIR"), "/cppdriver_data_collection.rs"));
 
 #[derive(Clone)]
 pub struct CassCollection {
     pub collection_type: CassCollectionType,
+    pub data_type: Option<CassDataTypeArc>,
     pub capacity: usize,
     pub items: Vec<CqlValue>,
 }
 
 impl CassCollection {
+    fn get_item_type(&self) -> Option<&CassDataTypeArc> {
+        match &**self.data_type.as_ref()? {
+            CassDataType::List(item_type) | CassDataType::Set(item_type) => item_type.as_ref(),
+            // Keys and values of a map are appended alternately.
+            CassDataType::Map(key_type, value_type) => {
+                if self.items.len() % 2 == 0 {
+                    key_type.as_ref()
+                } else {
+                    value_type.as_ref()
+                }
+            }
+            _ => None,
+        }
+    }
+
     pub fn append_cql_value(&mut self, value: Option<CqlValue>) -> CassError {
-        // FIXME: Bounds check, type check
-        // There is no API to append null, so unwrap is safe
-        self.items.push(value.unwrap());
+        // FIXME: Bounds check
+        // If the collection was created using `cass_collection_new_from_data_type`,
+        // we check if the value is compatible with the type of collection items.
+        if let Some(item_type) = self.get_item_type() {
+            if !is_compatible_type(item_type, &value) {
+                return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE;
+            }
+        }
+
+        // Collections cannot contain nulls (e.g. a null nested collection).
+        match value {
+            Some(v) => self.items.push(v),
+            None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+        }
         CassError::CASS_OK
     }
 }
@@ -61,8 +91,43 @@ pub unsafe extern "C" fn cass_collection_new(
         _ => item_count,
     } as usize;
 
+    // Collections created without a data type carry only the kind of the collection.
+    // The type is owned by the collection, so `cass_collection_data_type` returns
+    // a pointer which can be passed to functions taking a data type.
+    let data_type = match collection_type {
+        CassCollectionType::CASS_COLLECTION_TYPE_LIST => Some(CassDataType::List(None)),
+        CassCollectionType::CASS_COLLECTION_TYPE_SET => Some(CassDataType::Set(None)),
+        CassCollectionType::CASS_COLLECTION_TYPE_MAP => Some(CassDataType::Map(None, None)),
+        _ => None,
+    };
+
+    Box::into_raw(Box::new(CassCollection {
+        collection_type,
+        data_type: data_type.map(Arc::new),
+        capacity,
+        items: Vec::with_capacity(capacity),
+    }))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_collection_new_from_data_type(
+    data_type: *const CassDataType,
+    item_count: size_t,
+) -> *mut CassCollection {
+    let data_type = clone_arced(data_type);
+    let (collection_type, capacity) = match &*data_type {
+        CassDataType::List(_) => (CassCollectionType::CASS_COLLECTION_TYPE_LIST, item_count),
+        CassDataType::Set(_) => (CassCollectionType::CASS_COLLECTION_TYPE_SET, item_count),
+        // Maps consist of a key and a value, so twice
+        // the number of CqlValue will be stored.
+        CassDataType::Map(..) => (CassCollectionType::CASS_COLLECTION_TYPE_MAP, item_count * 2),
+        _ => return std::ptr::null_mut(),
+    };
+    let capacity = capacity as usize;
+
     Box::into_raw(Box::new(CassCollection {
         collection_type,
+        data_type: Some(data_type),
         capacity,
         items: Vec::with_capacity(capacity),
     }))
@@ -73,6 +138,18 @@ pub unsafe extern "C" fn cass_collection_free(collection: *mut CassCollection) {
     free_boxed(collection);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_collection_data_type(
+    collection: *const CassCollection,
+) -> *const CassDataType {
+    let collection_ref = ptr_to_ref(collection);
+
+    match &collection_ref.data_type {
+        Some(t) => Arc::as_ptr(t),
+        None => std::ptr::null(),
+    }
+}
+
 prepare_binders_macro!(@append CassCollection, |collection: &mut CassCollection, v| collection.append_cql_value(v));
 make_binders!(int8, cass_collection_append_int8);
 make_binders!(int16, cass_collection_append_int16);
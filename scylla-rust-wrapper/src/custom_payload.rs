@@ -0,0 +1,101 @@
+use crate::argconv::*;
+use crate::cass_error::CassError;
+use crate::future::CassFuture;
+use crate::types::*;
+use std::collections::BTreeMap;
+use std::os::raw::c_char;
+
+#[derive(Clone, Default)]
+pub struct CassCustomPayload {
+    // Items are kept sorted by name (as in the Cpp Driver, which uses an ordered map),
+    // so iteration order is deterministic. Setting an existing name overwrites its value.
+    pub items: BTreeMap<String, Vec<u8>>,
+}
+
+#[no_mangle]
+pub extern "C" fn cass_custom_payload_new() -> *mut CassCustomPayload {
+    Box::into_raw(Box::default())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_custom_payload_free(payload: *mut CassCustomPayload) {
+    free_boxed(payload);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_custom_payload_set(
+    payload: *mut CassCustomPayload,
+    name: *const c_char,
+    value: *const cass_byte_t,
+    value_size: size_t,
+) {
+    cass_custom_payload_set_n(payload, name, strlen(name), value, value_size)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_custom_payload_set_n(
+    payload: *mut CassCustomPayload,
+    name: *const c_char,
+    name_length: size_t,
+    value: *const cass_byte_t,
+    value_size: size_t,
+) {
+    let payload = ptr_to_ref_mut(payload);
+    // The function returns no error, so an invalid name is ignored.
+    let name = match ptr_to_cstr_n(name, name_length) {
+        Some(name) => name,
+        None => return,
+    };
+    let value = if value_size == 0 {
+        Vec::new()
+    } else {
+        std::slice::from_raw_parts(value, value_size as usize).to_vec()
+    };
+
+    payload.items.insert(name.to_string(), value);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_custom_payload_remove(
+    payload: *mut CassCustomPayload,
+    name: *const c_char,
+) {
+    cass_custom_payload_remove_n(payload, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_custom_payload_remove_n(
+    payload: *mut CassCustomPayload,
+    name: *const c_char,
+    name_length: size_t,
+) {
+    let payload = ptr_to_ref_mut(payload);
+    // The function returns no error, so an invalid name is ignored.
+    let name = match ptr_to_cstr_n(name, name_length) {
+        Some(name) => name,
+        None => return,
+    };
+
+    payload.items.remove(name);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_custom_payload_item(
+    _future: *mut CassFuture,
+    _index: size_t,
+    _name: *mut *const c_char,
+    _name_length: *mut size_t,
+    _value: *mut *const cass_byte_t,
+    _value_size: *mut size_t,
+) -> CassError {
+    // FIXME: Rust Driver does not expose the custom payload of a response,
+    // so there are no items to write to the out-params.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_custom_payload_item_count(_future: *mut CassFuture) -> size_t {
+    // FIXME: Rust Driver does not expose the custom payload of a response,
+    // so there are no items to return.
+    0
+}
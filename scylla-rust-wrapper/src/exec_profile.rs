@@ -0,0 +1,174 @@
+use crate::argconv::*;
+use crate::cass_error::CassError;
+use crate::cass_types::CassConsistency;
+use crate::types::*;
+use scylla::frame::types::{Consistency, SerialConsistency};
+use std::convert::TryInto;
+use std::os::raw::{c_char, c_int, c_uint};
+
+// Settings of the profile which are `None` are not overridden by the profile,
+// so the ones configured for the cluster are used.
+// Paging size is not a part of execution profiles (as in Cpp Driver) -
+// it is configured only per statement, with paging disabled by default.
+#[derive(Clone, Default)]
+pub struct CassExecProfile {
+    pub consistency: Option<Consistency>,
+    pub serial_consistency: Option<SerialConsistency>,
+    pub request_timeout_ms: Option<cass_uint64_t>,
+}
+
+#[no_mangle]
+pub extern "C" fn cass_execution_profile_new() -> *mut CassExecProfile {
+    Box::into_raw(Box::default())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_free(profile: *mut CassExecProfile) {
+    free_boxed(profile);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_consistency(
+    profile: *mut CassExecProfile,
+    consistency: CassConsistency,
+) -> CassError {
+    let profile = ptr_to_ref_mut(profile);
+    let consistency = match consistency.try_into().ok() {
+        Some(c) => c,
+        None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+    profile.consistency = Some(consistency);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_serial_consistency(
+    profile: *mut CassExecProfile,
+    serial_consistency: CassConsistency,
+) -> CassError {
+    let profile = ptr_to_ref_mut(profile);
+    let serial_consistency = match serial_consistency.try_into().ok() {
+        Some(c) => c,
+        None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+    profile.serial_consistency = Some(serial_consistency);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_constant_speculative_execution_policy(
+    _profile: *mut CassExecProfile,
+    _constant_delay_ms: cass_int64_t,
+    _max_speculative_executions: c_int,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set speculative execution policy per request,
+    // so only the cluster's policy can be used.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_no_speculative_execution_policy(
+    _profile: *mut CassExecProfile,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set speculative execution policy per request,
+    // so only the cluster's policy can be used.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_request_timeout(
+    profile: *mut CassExecProfile,
+    timeout_ms: cass_uint64_t,
+) -> CassError {
+    let profile = ptr_to_ref_mut(profile);
+
+    // CASS_UINT64_MAX disables the profile's timeout, so the default one is used.
+    if timeout_ms == cass_uint64_t::MAX {
+        profile.request_timeout_ms = None;
+        return CassError::CASS_OK;
+    }
+
+    // The same limit as for statements, see cass_statement_set_request_timeout.
+    let request_timeout_limit = (2_u64.pow(36) - 1) as u64;
+    if timeout_ms >= request_timeout_limit {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    profile.request_timeout_ms = Some(timeout_ms);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_token_aware_routing(
+    _profile: *mut CassExecProfile,
+    _enabled: cass_bool_t,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set load balancing policy per request,
+    // so only the cluster's policy can be used.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_load_balance_round_robin(
+    _profile: *mut CassExecProfile,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set load balancing policy per request,
+    // so only the cluster's policy can be used.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_load_balance_dc_aware(
+    profile: *mut CassExecProfile,
+    local_dc: *const c_char,
+    used_hosts_per_remote_dc: c_uint,
+    allow_remote_dcs_for_local_cl: cass_bool_t,
+) -> CassError {
+    cass_execution_profile_set_load_balance_dc_aware_n(
+        profile,
+        local_dc,
+        strlen(local_dc),
+        used_hosts_per_remote_dc,
+        allow_remote_dcs_for_local_cl,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_load_balance_dc_aware_n(
+    _profile: *mut CassExecProfile,
+    _local_dc_raw: *const c_char,
+    _local_dc_length: size_t,
+    _used_hosts_per_remote_dc: c_uint,
+    _allow_remote_dcs_for_local_cl: cass_bool_t,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set load balancing policy per request,
+    // so only the cluster's policy can be used.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_latency_aware_routing(
+    _profile: *mut CassExecProfile,
+    _enabled: cass_bool_t,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set load balancing policy per request,
+    // so latency-aware routing of the profile cannot be applied.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_profile_set_latency_aware_routing_settings(
+    _profile: *mut CassExecProfile,
+    _exclusion_threshold: cass_double_t,
+    _scale_ms: cass_uint64_t,
+    _retry_period_ms: cass_uint64_t,
+    _update_rate_ms: cass_uint64_t,
+    _min_measured: cass_uint64_t,
+) -> CassError {
+    // FIXME: Rust Driver does not allow to set load balancing policy per request,
+    // so latency-aware routing of the profile cannot be applied.
+    CassError::CASS_ERROR_LIB_NOT_IMPLEMENTED
+}
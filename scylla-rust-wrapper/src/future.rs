@@ -1,7 +1,7 @@
 use crate::argconv::*;
 use crate::cass_error::CassError;
 use crate::cass_error::CassErrorMessage;
-use crate::prepared::CassPrepared;
+use crate::prepared::{prepared_into_raw, CassPrepared};
 use crate::query_error::{CassErrorResult, CassErrorResult_};
 use crate::query_result::{CassResult, CassResult_};
 use crate::types::*;
@@ -229,7 +229,7 @@ pub unsafe extern "C" fn cass_future_get_prepared(
                 _ => None,
             }
         })
-        .map_or(std::ptr::null(), Arc::into_raw)
+        .map_or(std::ptr::null(), prepared_into_raw)
 }
 
 #[no_mangle]
@@ -0,0 +1,122 @@
+use crate::inet::CassInet;
+use crate::session::CassSession;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::os::raw::c_void;
+use std::sync::Weak;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+include!(concat!(env!("OUT_DIR"), "/cppdriver_host_listener.rs"));
+
+pub type CassHostListenerCallback = Option<
+    unsafe extern "C" fn(event: CassHostListenerEvent, address: CassInet, data: *mut c_void),
+>;
+
+// Rust Driver does not notify about changes of the cluster state, so the nodes
+// known to the session are polled and the changes since the previous poll are reported.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy)]
+pub struct HostListener {
+    callback: unsafe extern "C" fn(CassHostListenerEvent, CassInet, *mut c_void),
+    data: *mut c_void,
+}
+
+// *mut c_void is not Send, so Rust will have to take our word
+// that we won't screw something up
+unsafe impl Send for HostListener {}
+
+impl HostListener {
+    pub fn new(callback: CassHostListenerCallback, data: *mut c_void) -> Option<HostListener> {
+        callback.map(|callback| HostListener { callback, data })
+    }
+
+    fn notify(&self, event: CassHostListenerEvent, address: &SocketAddr) {
+        unsafe { (self.callback)(event, address.ip().into(), self.data) }
+    }
+
+    // Nodes are mapped to whether they are up.
+    fn notify_changes(
+        &self,
+        previous_nodes: &HashMap<SocketAddr, bool>,
+        nodes: &HashMap<SocketAddr, bool>,
+    ) {
+        for (address, is_up) in nodes {
+            match previous_nodes.get(address) {
+                None => self.notify(CassHostListenerEvent::CASS_HOST_LISTENER_EVENT_ADD, address),
+                Some(was_up) if was_up == is_up => {}
+                Some(_) if *is_up => {
+                    self.notify(CassHostListenerEvent::CASS_HOST_LISTENER_EVENT_UP, address)
+                }
+                Some(_) => self.notify(
+                    CassHostListenerEvent::CASS_HOST_LISTENER_EVENT_DOWN,
+                    address,
+                ),
+            }
+        }
+
+        for address in previous_nodes.keys() {
+            if !nodes.contains_key(address) {
+                self.notify(
+                    CassHostListenerEvent::CASS_HOST_LISTENER_EVENT_REMOVE,
+                    address,
+                );
+            }
+        }
+    }
+}
+
+pub struct HostListenerTask {
+    poll_task: JoinHandle<()>,
+}
+
+impl HostListenerTask {
+    // Must be called within the Tokio runtime, as it spawns the polling task.
+    // Nodes known when the session is connected are not reported.
+    pub fn new(listener: HostListener, session: Weak<CassSession>) -> HostListenerTask {
+        let poll_task = tokio::spawn(async move {
+            let mut previous_nodes: Option<HashMap<SocketAddr, bool>> = None;
+            let mut interval = tokio::time::interval(POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+
+                let session = match session.upgrade() {
+                    Some(session) => session,
+                    None => return,
+                };
+                // The guard is dropped before the callback is called, so the callback
+                // may use the session (e.g. close it) without a deadlock.
+                let nodes: HashMap<SocketAddr, bool> = {
+                    let session_guard = session.read().await;
+                    let session_inner = match session_guard.as_ref() {
+                        Some(session_inner) => session_inner,
+                        None => return,
+                    };
+                    session_inner
+                        .session
+                        .get_cluster_data()
+                        .get_nodes_info()
+                        .iter()
+                        .map(|node| (node.address, !node.is_down()))
+                        .collect()
+                };
+                drop(session);
+
+                if let Some(previous_nodes) = &previous_nodes {
+                    listener.notify_changes(previous_nodes, &nodes);
+                }
+                previous_nodes = Some(nodes);
+            }
+        });
+
+        HostListenerTask { poll_task }
+    }
+}
+
+impl Drop for HostListenerTask {
+    fn drop(&mut self) {
+        // The task is stopped when the session is closed or freed.
+        self.poll_task.abort();
+    }
+}
@@ -16,15 +16,20 @@ pub mod cass_error;
 pub mod cass_types;
 pub mod cluster;
 pub mod collection;
+pub mod custom_payload;
+pub mod exec_profile;
 mod external;
 pub mod future;
+pub mod host_listener;
 pub mod inet;
 mod logging;
 pub mod metadata;
+pub mod metrics;
 pub mod prepared;
 pub mod query_error;
 pub mod query_result;
 pub mod retry_policy;
+pub mod schema_fields;
 pub mod session;
 pub mod ssl;
 pub mod statement;
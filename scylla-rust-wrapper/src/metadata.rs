@@ -1,9 +1,11 @@
 use crate::argconv::*;
 use crate::cass_types::get_column_type_from_cql_type;
-use crate::cass_types::CassDataType;
+use crate::cass_types::{CassDataType, CassValueType};
+use crate::query_result::{CassValue, Collection, Value};
 use crate::types::*;
-use scylla::transport::topology::{ColumnKind, CqlType, Table};
-use std::collections::HashMap;
+use scylla::frame::response::result::CqlValue;
+use scylla::transport::topology::{ColumnKind, CqlType, Strategy, Table};
+use std::collections::{BTreeMap, HashMap};
 use std::os::raw::c_char;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -25,6 +27,7 @@ pub struct CassKeyspaceMeta {
     pub user_defined_type_data_type: HashMap<String, Arc<CassDataType>>,
     pub tables: HashMap<String, Arc<CassTableMeta>>,
     pub views: HashMap<String, Arc<CassMaterializedViewMeta>>,
+    pub fields: CassMetaFields,
 }
 
 pub type CassTableMeta_ = &'static CassTableMeta;
@@ -35,6 +38,7 @@ pub struct CassTableMeta {
     pub partition_keys: Vec<String>,
     pub clustering_keys: Vec<String>,
     pub views: HashMap<String, Arc<CassMaterializedViewMeta>>,
+    pub fields: CassMetaFields,
 }
 
 pub type CassMaterializedViewMeta_ = &'static CassMaterializedViewMeta;
@@ -51,6 +55,93 @@ pub struct CassColumnMeta {
     pub column_kind: CassColumnType,
 }
 
+// Raw schema fields, named after the columns of `system_schema` tables.
+// Fields of keyspaces and tables are filled with the rows of `system_schema.keyspaces`
+// and `system_schema.tables` cached by the session (see `SchemaFieldsTask`), on top of
+// the ones recreated from the metadata of Rust Driver, which are available even if
+// the rows could not be queried.
+pub type CassMetaFields = BTreeMap<String, CassValue>;
+
+fn create_text_field(text: &str) -> CassValue {
+    CassValue::new(
+        Some(Value::RegularValue(CqlValue::Text(text.to_owned()))),
+        Arc::new(CassDataType::Value(CassValueType::CASS_VALUE_TYPE_VARCHAR)),
+    )
+}
+
+fn create_replication_field(strategy: &Strategy) -> CassValue {
+    let mut replication: Vec<(String, String)> = match strategy {
+        Strategy::SimpleStrategy { replication_factor } => vec![
+            (
+                "class".to_owned(),
+                "org.apache.cassandra.locator.SimpleStrategy".to_owned(),
+            ),
+            (
+                "replication_factor".to_owned(),
+                replication_factor.to_string(),
+            ),
+        ],
+        Strategy::NetworkTopologyStrategy {
+            datacenter_repfactors,
+        } => std::iter::once((
+            "class".to_owned(),
+            "org.apache.cassandra.locator.NetworkTopologyStrategy".to_owned(),
+        ))
+        .chain(
+            datacenter_repfactors
+                .iter()
+                .map(|(dc, replication_factor)| (dc.clone(), replication_factor.to_string())),
+        )
+        .collect(),
+        Strategy::LocalStrategy => vec![(
+            "class".to_owned(),
+            "org.apache.cassandra.locator.LocalStrategy".to_owned(),
+        )],
+        Strategy::Other { name, data } => std::iter::once(("class".to_owned(), name.clone()))
+            .chain(data.iter().map(|(key, value)| (key.clone(), value.clone())))
+            .collect(),
+    };
+    // Entries of a map are sorted by key, as they are returned by the server.
+    replication.sort();
+
+    let text_type = Arc::new(CassDataType::Value(CassValueType::CASS_VALUE_TYPE_VARCHAR));
+    let entries = replication
+        .iter()
+        .map(|(key, value)| (create_text_field(key), create_text_field(value)))
+        .collect();
+
+    CassValue::new(
+        Some(Value::CollectionValue(Collection::Map(entries))),
+        Arc::new(CassDataType::Map(Some(text_type.clone()), Some(text_type))),
+    )
+}
+
+pub fn create_keyspace_fields(keyspace_name: &str, strategy: &Strategy) -> CassMetaFields {
+    let mut fields = BTreeMap::new();
+    fields.insert("keyspace_name".to_owned(), create_text_field(keyspace_name));
+    fields.insert("replication".to_owned(), create_replication_field(strategy));
+
+    fields
+}
+
+// FIXME: Rust Driver does not expose the `WHERE` clause of a view,
+// nor whether it includes all columns of the base table.
+pub fn create_view_fields(
+    keyspace_name: &str,
+    view_name: &str,
+    base_table_name: &str,
+) -> CassMetaFields {
+    let mut fields = BTreeMap::new();
+    fields.insert("keyspace_name".to_owned(), create_text_field(keyspace_name));
+    fields.insert("view_name".to_owned(), create_text_field(view_name));
+    fields.insert(
+        "base_table_name".to_owned(),
+        create_text_field(base_table_name),
+    );
+
+    fields
+}
+
 pub unsafe fn create_table_metadata(
     keyspace_name: &str,
     table_name: &str,
@@ -80,12 +171,17 @@ pub unsafe fn create_table_metadata(
             columns_metadata.insert(column_name.clone(), cass_column_meta);
         });
 
+    let mut fields = BTreeMap::new();
+    fields.insert("keyspace_name".to_owned(), create_text_field(keyspace_name));
+    fields.insert("table_name".to_owned(), create_text_field(table_name));
+
     CassTableMeta {
         name: table_name.to_owned(),
         columns_metadata,
         partition_keys: table_metadata.partition_key.clone(),
         clustering_keys: table_metadata.clustering_key.clone(),
         views: HashMap::new(),
+        fields,
     }
 }
 
@@ -283,6 +379,35 @@ pub unsafe extern "C" fn cass_table_meta_column_by_name_n(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_field_by_name(
+    table_meta: *const CassTableMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_table_meta_field_by_name_n(table_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_field_by_name_n(
+    table_meta: *const CassTableMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let table_meta = ptr_to_ref(table_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    // Table options (e.g. `compaction` or `gc_grace_seconds`) are fields as well,
+    // because they are columns of `system_schema.tables`.
+    match table_meta.fields.get(field_name) {
+        Some(field_value) => field_value as *const CassValue,
+        None => std::ptr::null(),
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_column_meta_name(
     column_meta: *const CassColumnMeta,
@@ -426,7 +551,40 @@ pub unsafe extern "C" fn cass_materialized_view_meta_base_table(
     view_meta: *const CassMaterializedViewMeta,
 ) -> *const CassTableMeta {
     let view_meta = ptr_to_ref(view_meta);
-    view_meta.base_table.as_ptr()
+
+    // Base table is owned by the same schema metadata as the view, so the pointer
+    // stays valid after the temporary Arc is dropped.
+    match view_meta.base_table.upgrade() {
+        Some(base_table) => Arc::as_ptr(&base_table),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_materialized_view_meta_field_by_name(
+    view_meta: *const CassMaterializedViewMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_materialized_view_meta_field_by_name_n(view_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_materialized_view_meta_field_by_name_n(
+    view_meta: *const CassMaterializedViewMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let view_meta = ptr_to_ref(view_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match view_meta.view_metadata.fields.get(field_name) {
+        Some(field_value) => field_value as *const CassValue,
+        None => std::ptr::null(),
+    }
 }
 
 #[no_mangle]
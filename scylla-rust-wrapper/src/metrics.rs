@@ -0,0 +1,169 @@
+use crate::argconv::*;
+use crate::session::CassSession;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+include!(concat!(env!("OUT_DIR"), "/cppdriver_metrics_types.rs"));
+
+const SUB_BUCKET_BITS: u32 = 4;
+const SUB_BUCKETS: usize = 1 << SUB_BUCKET_BITS;
+// Values below SUB_BUCKETS are kept exactly, the rest in groups of SUB_BUCKETS sub-buckets,
+// one group for each possible position of the most significant bit.
+const BUCKETS: usize = (64 - SUB_BUCKET_BITS as usize + 1) * SUB_BUCKETS;
+
+// Latencies of requests in microseconds. Rust Driver records latencies in whole
+// milliseconds, so they are recorded by the session itself with a better precision.
+// Buckets are logarithmic and split into linear sub-buckets, so the relative error
+// of percentiles is bounded by 1/SUB_BUCKETS.
+pub struct LatencyHistogram {
+    buckets: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum: AtomicU64,
+    min: AtomicU64,
+    max: AtomicU64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> LatencyHistogram {
+        LatencyHistogram {
+            buckets: (0..BUCKETS).map(|_| AtomicU64::new(0)).collect(),
+            count: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+            min: AtomicU64::new(u64::MAX),
+            max: AtomicU64::new(0),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    pub fn record(&self, latency: Duration) {
+        let value = latency.as_micros().min(u64::MAX as u128) as u64;
+
+        self.buckets[bucket_index(value)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(value, Ordering::Relaxed);
+        self.min.fetch_min(value, Ordering::Relaxed);
+        self.max.fetch_max(value, Ordering::Relaxed);
+    }
+
+    fn percentile(&self, percentile: f64, count: u64) -> u64 {
+        let target = ((count as f64 * percentile / 100.0).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (index, bucket) in self.buckets.iter().enumerate() {
+            seen += bucket.load(Ordering::Relaxed);
+            if seen >= target {
+                return bucket_lower_bound(index);
+            }
+        }
+
+        self.max.load(Ordering::Relaxed)
+    }
+}
+
+fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKETS as u64 {
+        return value as usize;
+    }
+
+    let shift = 63 - value.leading_zeros() - SUB_BUCKET_BITS;
+    let sub_bucket = (value >> shift) as usize & (SUB_BUCKETS - 1);
+    (shift as usize + 1) * SUB_BUCKETS + sub_bucket
+}
+
+fn bucket_lower_bound(index: usize) -> u64 {
+    let group = index / SUB_BUCKETS;
+    let sub_bucket = index % SUB_BUCKETS;
+    if group == 0 {
+        sub_bucket as u64
+    } else {
+        ((SUB_BUCKETS + sub_bucket) as u64) << (group - 1)
+    }
+}
+
+// Metrics of the session refreshed periodically by a background task,
+// so reading them is cheap and all the values come from the same moment.
+pub struct MetricsSnapshot {
+    metrics: Arc<Mutex<CassMetrics>>,
+    refresh_task: JoinHandle<()>,
+}
+
+impl MetricsSnapshot {
+    // Must be called within the Tokio runtime, as it spawns the refreshing task.
+    pub fn new(latencies: Arc<LatencyHistogram>, refresh_interval: Duration) -> MetricsSnapshot {
+        let metrics = Arc::new(Mutex::new(create_cass_metrics(&latencies)));
+        let metrics_clone = metrics.clone();
+
+        let refresh_task = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(refresh_interval);
+            loop {
+                interval.tick().await;
+                *metrics_clone.lock().unwrap() = create_cass_metrics(&latencies);
+            }
+        });
+
+        MetricsSnapshot {
+            metrics,
+            refresh_task,
+        }
+    }
+
+    fn get(&self) -> CassMetrics {
+        *self.metrics.lock().unwrap()
+    }
+}
+
+impl Drop for MetricsSnapshot {
+    fn drop(&mut self) {
+        // The task is stopped when the session is closed or freed.
+        self.refresh_task.abort();
+    }
+}
+
+fn create_cass_metrics(latencies: &LatencyHistogram) -> CassMetrics {
+    // Metrics which are not tracked by the Rust Driver (e.g. request rates
+    // or connection statistics) are reported as 0.
+    let mut metrics: CassMetrics = unsafe { std::mem::zeroed() };
+
+    let count = latencies.count.load(Ordering::Relaxed);
+    if count == 0 {
+        return metrics;
+    }
+
+    metrics.requests.min = latencies.min.load(Ordering::Relaxed);
+    metrics.requests.max = latencies.max.load(Ordering::Relaxed);
+    metrics.requests.mean = latencies.sum.load(Ordering::Relaxed) / count;
+    metrics.requests.median = latencies.percentile(50.0, count);
+    metrics.requests.percentile_75th = latencies.percentile(75.0, count);
+    metrics.requests.percentile_95th = latencies.percentile(95.0, count);
+    metrics.requests.percentile_98th = latencies.percentile(98.0, count);
+    metrics.requests.percentile_99th = latencies.percentile(99.0, count);
+    metrics.requests.percentile_999th = latencies.percentile(99.9, count);
+
+    metrics
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_session_get_metrics(
+    session_raw: *const CassSession,
+    metrics: *mut CassMetrics,
+) {
+    let session_opt = ptr_to_ref(session_raw);
+    let metrics = ptr_to_ref_mut(metrics);
+
+    let session_guard = session_opt.blocking_read();
+    let session_inner = match session_guard.as_ref() {
+        Some(session_inner) => session_inner,
+        None => {
+            *metrics = std::mem::zeroed();
+            return;
+        }
+    };
+
+    // Without histogram refresh interval, metrics are computed on each call.
+    *metrics = match &session_inner.metrics_snapshot {
+        Some(snapshot) => snapshot.get(),
+        None => create_cass_metrics(&session_inner.latencies),
+    };
+}
@@ -1,5 +1,10 @@
 use scylla::frame::value::MaybeUnset::Unset;
 use std::sync::Arc;
+#[cfg(debug_assertions)]
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
 
 use crate::{
     argconv::*,
@@ -9,8 +14,52 @@ use scylla::prepared_statement::PreparedStatement;
 
 pub type CassPrepared = PreparedStatement;
 
+// In debug builds, every CassPrepared pointer handed out to the user is counted,
+// so that freeing the same pointer more times than it was obtained (a double free,
+// which would corrupt the Arc's reference count) is detected. The process is aborted
+// then, because a panic must not unwind through `extern "C"` functions.
+#[cfg(debug_assertions)]
+lazy_static::lazy_static! {
+    static ref PREPARED_HANDLES: Mutex<HashMap<usize, usize>> = Mutex::new(HashMap::new());
+}
+
+// The map is left consistent by every critical section, so a poisoned lock is still usable.
+#[cfg(debug_assertions)]
+fn lock_prepared_handles() -> MutexGuard<'static, HashMap<usize, usize>> {
+    PREPARED_HANDLES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub fn prepared_into_raw(prepared: Arc<CassPrepared>) -> *const CassPrepared {
+    let prepared_raw = Arc::into_raw(prepared);
+
+    #[cfg(debug_assertions)]
+    {
+        let mut handles = lock_prepared_handles();
+        *handles.entry(prepared_raw as usize).or_insert(0) += 1;
+    }
+
+    prepared_raw
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_prepared_free(prepared_raw: *const CassPrepared) {
+    #[cfg(debug_assertions)]
+    if !prepared_raw.is_null() {
+        let mut handles = lock_prepared_handles();
+        match handles.get_mut(&(prepared_raw as usize)) {
+            Some(count) if *count > 1 => *count -= 1,
+            Some(_) => {
+                handles.remove(&(prepared_raw as usize));
+            }
+            None => {
+                tracing::error!("Double free of CassPrepared detected (cass_prepared_free)");
+                std::process::abort();
+            }
+        }
+    }
+
     free_arced(prepared_raw);
 }
 
@@ -30,5 +79,10 @@ pub unsafe extern "C" fn cass_prepared_bind(
         bound_values: vec![Unset; bound_values_size],
         paging_state: None,
         request_timeout_ms: None,
+        consistency: None,
+        serial_consistency: None,
+        custom_payload: None,
+        exec_profile: None,
+        lazy_rows: false,
     }))
 }
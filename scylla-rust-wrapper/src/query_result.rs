@@ -6,25 +6,43 @@ use crate::metadata::{
     CassColumnMeta, CassKeyspaceMeta, CassKeyspaceMeta_, CassMaterializedViewMeta,
     CassMaterializedViewMeta_, CassSchemaMeta, CassSchemaMeta_, CassTableMeta, CassTableMeta_,
 };
+use crate::session::create_cass_row_columns;
 use crate::statement::CassStatement;
 use crate::types::*;
 use crate::uuid::CassUuid;
-use scylla::frame::response::result::{ColumnSpec, CqlValue};
+use scylla::frame::response::result::{ColumnSpec, CqlValue, Row};
+use scylla::frame::value::{Value as _, ValueTooBig};
 use scylla::{BufMut, Bytes, BytesMut};
 use std::convert::TryInto;
 use std::os::raw::c_char;
 use std::slice;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, OnceLock};
 use uuid::Uuid;
 
+include!(concat!(env!("OUT_DIR"), "/cppdriver_iterator_types.rs"));
+
 pub struct CassResult {
     pub rows: Option<Vec<CassRow>>,
     pub metadata: Arc<CassResultData>,
 }
 
+impl CassResult {
+    // Void results (e.g. of an INSERT) have no rows at all, so they have no first row,
+    // the same as rows results with no rows in the page.
+    pub fn first_row(&self) -> Option<&CassRow> {
+        self.rows.as_ref().and_then(|rows| rows.first())
+    }
+}
+
 pub struct CassResultData {
     pub paging_state: Option<Bytes>,
+    // Encoded on the first call to cass_result_paging_state_token_base64 and kept
+    // alongside the raw paging state, so the returned pointer lives as long as the result.
+    pub paging_state_base64: OnceLock<String>,
     pub col_specs: Vec<ColumnSpec>,
+    // Types of the columns (in the order of `col_specs`), shared by values of all rows,
+    // so the pointer returned by cass_result_column_data_type lives as long as the result.
+    pub col_data_types: Vec<CassDataTypeArc>,
     pub tracing_id: Option<Uuid>,
 }
 
@@ -38,13 +56,64 @@ pub type CassRow_ = &'static CassRow;
 pub type CassValue_ = &'static CassValue;
 
 pub struct CassRow {
-    pub columns: Vec<CassValue>,
+    columns: CassRowColumns,
     pub result_metadata: Arc<CassResultData>,
 }
 
+enum CassRowColumns {
+    Eager(Vec<CassValue>),
+    // Values of rows of results of statements with lazy rows enabled are built from
+    // the raw row on first access, so reading a few rows of a large page does not
+    // convert all of them. The state is boxed to keep eager rows as small as before.
+    Lazy(Box<LazyCassRowColumns>),
+}
+
+struct LazyCassRowColumns {
+    columns: OnceLock<Vec<CassValue>>,
+    raw_row: Mutex<Option<Row>>,
+}
+
+impl CassRow {
+    pub fn new(row: Row, result_metadata: Arc<CassResultData>, lazy: bool) -> CassRow {
+        let columns = if lazy {
+            CassRowColumns::Lazy(Box::new(LazyCassRowColumns {
+                columns: OnceLock::new(),
+                raw_row: Mutex::new(Some(row)),
+            }))
+        } else {
+            CassRowColumns::Eager(create_cass_row_columns(row, &result_metadata))
+        };
+
+        CassRow {
+            columns,
+            result_metadata,
+        }
+    }
+
+    pub fn columns(&self) -> &Vec<CassValue> {
+        match &self.columns {
+            CassRowColumns::Eager(columns) => columns,
+            CassRowColumns::Lazy(lazy) => lazy.columns.get_or_init(|| {
+                // The raw row is taken only once, by the initialization of `columns`.
+                let raw_row = lazy
+                    .raw_row
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .take();
+                raw_row
+                    .map(|row| create_cass_row_columns(row, &self.result_metadata))
+                    .unwrap_or_default()
+            }),
+        }
+    }
+}
+
 pub enum Value {
     RegularValue(CqlValue),
     CollectionValue(Collection),
+    // Decimals are kept in their serialized form (unscaled varint and scale),
+    // so the varint returned by cass_value_get_decimal lives as long as the value.
+    DecimalValue { varint: Vec<u8>, scale: i32 },
 }
 
 pub enum Collection {
@@ -62,6 +131,159 @@ pub enum Collection {
 pub struct CassValue {
     pub value: Option<Value>,
     pub value_type: CassDataTypeArc,
+    // Serialized form of the value, created on the first call to `cass_value_get_bytes`,
+    // so the returned bytes live as long as the value.
+    // None if the value could not be serialized.
+    raw_bytes: OnceLock<Option<Vec<u8>>>,
+}
+
+impl CassValue {
+    pub fn new(value: Option<Value>, value_type: CassDataTypeArc) -> CassValue {
+        CassValue {
+            value,
+            value_type,
+            raw_bytes: OnceLock::new(),
+        }
+    }
+}
+
+// Serializes the value the same way as in CQL protocol, i.e. [bytes] with 4-byte length.
+fn serialize_value(value: Option<&Value>, buf: &mut Vec<u8>) -> Result<(), ValueTooBig> {
+    match value {
+        None => {
+            buf.put_i32(-1);
+            Ok(())
+        }
+        Some(Value::RegularValue(cql_value)) => cql_value.serialize(buf),
+        Some(Value::DecimalValue { varint, scale }) => serialize_with_length(buf, |buf| {
+            buf.put_i32(*scale);
+            buf.put_slice(varint);
+            Ok(())
+        }),
+        Some(Value::CollectionValue(Collection::List(elements)))
+        | Some(Value::CollectionValue(Collection::Set(elements))) => {
+            serialize_with_length(buf, |buf| {
+                buf.put_i32(elements.len().try_into().map_err(|_| ValueTooBig)?);
+                for element in elements {
+                    serialize_value(element.value.as_ref(), buf)?;
+                }
+                Ok(())
+            })
+        }
+        Some(Value::CollectionValue(Collection::Map(entries))) => {
+            serialize_with_length(buf, |buf| {
+                buf.put_i32(entries.len().try_into().map_err(|_| ValueTooBig)?);
+                for (key, value) in entries {
+                    serialize_value(key.value.as_ref(), buf)?;
+                    serialize_value(value.value.as_ref(), buf)?;
+                }
+                Ok(())
+            })
+        }
+        Some(Value::CollectionValue(Collection::UserDefinedType { fields, .. })) => {
+            serialize_with_length(buf, |buf| {
+                for (_, field) in fields {
+                    serialize_value(field.as_ref().and_then(|f| f.value.as_ref()), buf)?;
+                }
+                Ok(())
+            })
+        }
+        Some(Value::CollectionValue(Collection::Tuple(fields))) => {
+            serialize_with_length(buf, |buf| {
+                for field in fields {
+                    serialize_value(field.as_ref().and_then(|f| f.value.as_ref()), buf)?;
+                }
+                Ok(())
+            })
+        }
+    }
+}
+
+fn serialize_with_length(
+    buf: &mut Vec<u8>,
+    serialize_contents: impl FnOnce(&mut Vec<u8>) -> Result<(), ValueTooBig>,
+) -> Result<(), ValueTooBig> {
+    // Length is known only after the contents are serialized.
+    let len_pos = buf.len();
+    buf.put_i32(0);
+    serialize_contents(buf)?;
+
+    let len: i32 = (buf.len() - len_pos - 4)
+        .try_into()
+        .map_err(|_| ValueTooBig)?;
+    buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+impl PartialEq for CassValue {
+    fn eq(&self, other: &Self) -> bool {
+        // Values of different types (including collections with different element types)
+        // are never equal, so types are compared first. Elements of a collection share
+        // the Arc of their type, so they are not compared structurally. NULL is equal
+        // only to NULL.
+        (Arc::ptr_eq(&self.value_type, &other.value_type) || self.value_type == other.value_type)
+            && self.value == other.value
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Floating point values are compared by their bit patterns (as their serialized
+            // forms would be), so NaN is equal to NaN with the same representation.
+            (Value::RegularValue(CqlValue::Float(a)), Value::RegularValue(CqlValue::Float(b))) => {
+                a.to_bits() == b.to_bits()
+            }
+            (
+                Value::RegularValue(CqlValue::Double(a)),
+                Value::RegularValue(CqlValue::Double(b)),
+            ) => a.to_bits() == b.to_bits(),
+            // Durations are compared component-wise, as their months and days
+            // have no fixed length (1 day is not equal to 24 hours).
+            (Value::RegularValue(a), Value::RegularValue(b)) => a == b,
+            (Value::CollectionValue(a), Value::CollectionValue(b)) => a == b,
+            // As in Cpp Driver, decimals are compared by their serialized form,
+            // so values with different scales (e.g. 1.0 and 1.00) are not equal.
+            (
+                Value::DecimalValue {
+                    varint: varint_a,
+                    scale: scale_a,
+                },
+                Value::DecimalValue {
+                    varint: varint_b,
+                    scale: scale_b,
+                },
+            ) => varint_a == varint_b && scale_a == scale_b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for Collection {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Collection::List(a), Collection::List(b)) => a == b,
+            // Elements of sets and keys of maps are always sent by the server sorted,
+            // so sets and maps with the same elements (regardless of the order they
+            // were inserted in) are compared element-wise in linear time.
+            (Collection::Set(a), Collection::Set(b)) => a == b,
+            (Collection::Map(a), Collection::Map(b)) => a == b,
+            (
+                Collection::UserDefinedType {
+                    keyspace: keyspace_a,
+                    type_name: type_name_a,
+                    fields: fields_a,
+                },
+                Collection::UserDefinedType {
+                    keyspace: keyspace_b,
+                    type_name: type_name_b,
+                    fields: fields_b,
+                },
+            ) => keyspace_a == keyspace_b && type_name_a == type_name_b && fields_a == fields_b,
+            (Collection::Tuple(a), Collection::Tuple(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 pub struct CassResultIterator {
@@ -126,8 +348,54 @@ pub enum CassIterator {
     CassKeyspaceMetaTableIterator(CassKeyspaceMetaIterator),
     CassKeyspaceMetaUserTypeIterator(CassKeyspaceMetaIterator),
     CassKeyspaceMetaViewIterator(CassKeyspaceMetaIterator),
+    CassKeyspaceMetaFieldIterator(CassKeyspaceMetaIterator),
     CassTableMetaIterator(CassTableMetaIterator),
+    CassTableMetaViewIterator(CassTableMetaIterator),
+    CassTableMetaFieldIterator(CassTableMetaIterator),
     CassViewMetaIterator(CassViewMetaIterator),
+    CassViewMetaFieldIterator(CassViewMetaIterator),
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_type(iterator: *mut CassIterator) -> CassIteratorType {
+    let iter = ptr_to_ref(iterator);
+
+    match iter {
+        CassIterator::CassResultIterator(_) => CassIteratorType::CASS_ITERATOR_TYPE_RESULT,
+        CassIterator::CassRowIterator(_) => CassIteratorType::CASS_ITERATOR_TYPE_ROW,
+        // Tuples are iterated the same way as lists and sets.
+        CassIterator::CassCollectionIterator(collection_iterator) => {
+            match collection_iterator.value.value {
+                Some(Value::CollectionValue(Collection::Tuple(_))) => {
+                    CassIteratorType::CASS_ITERATOR_TYPE_TUPLE
+                }
+                _ => CassIteratorType::CASS_ITERATOR_TYPE_COLLECTION,
+            }
+        }
+        CassIterator::CassMapIterator(_) => CassIteratorType::CASS_ITERATOR_TYPE_MAP,
+        CassIterator::CassUdtIterator(_) => CassIteratorType::CASS_ITERATOR_TYPE_USER_TYPE_FIELD,
+        CassIterator::CassSchemaMetaIterator(_) => {
+            CassIteratorType::CASS_ITERATOR_TYPE_KEYSPACE_META
+        }
+        CassIterator::CassKeyspaceMetaTableIterator(_) => {
+            CassIteratorType::CASS_ITERATOR_TYPE_TABLE_META
+        }
+        CassIterator::CassKeyspaceMetaUserTypeIterator(_) => {
+            CassIteratorType::CASS_ITERATOR_TYPE_TYPE_META
+        }
+        CassIterator::CassKeyspaceMetaViewIterator(_)
+        | CassIterator::CassTableMetaViewIterator(_) => {
+            CassIteratorType::CASS_ITERATOR_TYPE_MATERIALIZED_VIEW_META
+        }
+        CassIterator::CassTableMetaIterator(_) | CassIterator::CassViewMetaIterator(_) => {
+            CassIteratorType::CASS_ITERATOR_TYPE_COLUMN_META
+        }
+        CassIterator::CassKeyspaceMetaFieldIterator(_)
+        | CassIterator::CassTableMetaFieldIterator(_)
+        | CassIterator::CassViewMetaFieldIterator(_) => {
+            CassIteratorType::CASS_ITERATOR_TYPE_META_FIELD
+        }
+    }
 }
 
 #[no_mangle]
@@ -156,7 +424,7 @@ pub unsafe extern "C" fn cass_iterator_next(iterator: *mut CassIterator) -> cass
 
             row_iterator.position = Some(new_pos);
 
-            (new_pos < row_iterator.row.columns.len()) as cass_bool_t
+            (new_pos < row_iterator.row.columns().len()) as cass_bool_t
         }
         CassIterator::CassCollectionIterator(collection_iterator) => {
             let new_pos: usize = collection_iterator
@@ -208,7 +476,8 @@ pub unsafe extern "C" fn cass_iterator_next(iterator: *mut CassIterator) -> cass
 
             (new_pos < keyspace_meta_iterator.count) as cass_bool_t
         }
-        CassIterator::CassKeyspaceMetaViewIterator(keyspace_meta_iterator) => {
+        CassIterator::CassKeyspaceMetaViewIterator(keyspace_meta_iterator)
+        | CassIterator::CassKeyspaceMetaFieldIterator(keyspace_meta_iterator) => {
             let new_pos: usize = keyspace_meta_iterator
                 .position
                 .map_or(0, |prev_pos| prev_pos + 1);
@@ -217,14 +486,17 @@ pub unsafe extern "C" fn cass_iterator_next(iterator: *mut CassIterator) -> cass
 
             (new_pos < keyspace_meta_iterator.count) as cass_bool_t
         }
-        CassIterator::CassTableMetaIterator(table_iterator) => {
+        CassIterator::CassTableMetaIterator(table_iterator)
+        | CassIterator::CassTableMetaViewIterator(table_iterator)
+        | CassIterator::CassTableMetaFieldIterator(table_iterator) => {
             let new_pos: usize = table_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
 
             table_iterator.position = Some(new_pos);
 
             (new_pos < table_iterator.count) as cass_bool_t
         }
-        CassIterator::CassViewMetaIterator(view_iterator) => {
+        CassIterator::CassViewMetaIterator(view_iterator)
+        | CassIterator::CassViewMetaFieldIterator(view_iterator) => {
             let new_pos: usize = view_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
 
             view_iterator.position = Some(new_pos);
@@ -274,7 +546,7 @@ pub unsafe extern "C" fn cass_iterator_get_column(
             None => return std::ptr::null(),
         };
 
-        let value = match row_iterator.row.columns.get(iter_position) {
+        let value = match row_iterator.row.columns().get(iter_position) {
             Some(col) => col,
             None => return std::ptr::null(),
         };
@@ -586,7 +858,7 @@ pub unsafe extern "C" fn cass_iterator_get_materialized_view_meta(
                 None => std::ptr::null(),
             }
         }
-        CassIterator::CassTableMetaIterator(table_meta_iterator) => {
+        CassIterator::CassTableMetaViewIterator(table_meta_iterator) => {
             let iter_position = match table_meta_iterator.position {
                 Some(pos) => pos,
                 None => return std::ptr::null(),
@@ -603,6 +875,54 @@ pub unsafe extern "C" fn cass_iterator_get_materialized_view_meta(
     }
 }
 
+// Returns the field the iterator points to, if it iterates over fields of schema metadata.
+unsafe fn get_meta_field(
+    iterator: *const CassIterator,
+) -> Option<(&'static String, &'static CassValue)> {
+    let (fields, position) = match ptr_to_ref(iterator) {
+        CassIterator::CassKeyspaceMetaFieldIterator(keyspace_meta_iterator) => (
+            &keyspace_meta_iterator.value.fields,
+            keyspace_meta_iterator.position,
+        ),
+        CassIterator::CassTableMetaFieldIterator(table_meta_iterator) => (
+            &table_meta_iterator.value.fields,
+            table_meta_iterator.position,
+        ),
+        CassIterator::CassViewMetaFieldIterator(view_meta_iterator) => (
+            &view_meta_iterator.value.view_metadata.fields,
+            view_meta_iterator.position,
+        ),
+        _ => return None,
+    };
+
+    fields.iter().nth(position?)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_meta_field_name(
+    iterator: *const CassIterator,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) -> CassError {
+    match get_meta_field(iterator) {
+        Some((field_name, _)) => {
+            write_str_to_c(field_name.as_str(), name, name_length);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_meta_field_value(
+    iterator: *const CassIterator,
+) -> *const CassValue {
+    match get_meta_field(iterator) {
+        Some((_, field_value)) => field_value,
+        None => std::ptr::null(),
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_from_result(result: *const CassResult) -> *mut CassIterator {
     let result_from_raw: CassResult_ = clone_arced(result);
@@ -776,6 +1096,23 @@ pub unsafe extern "C" fn cass_iterator_user_types_from_keyspace_meta(
     )))
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_keyspace_meta(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> *mut CassIterator {
+    let metadata = ptr_to_ref(keyspace_meta);
+
+    let iterator = CassKeyspaceMetaIterator {
+        value: metadata,
+        count: metadata.fields.len(),
+        position: None,
+    };
+
+    Box::into_raw(Box::new(CassIterator::CassKeyspaceMetaFieldIterator(
+        iterator,
+    )))
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_columns_from_table_meta(
     table_meta: *const CassTableMeta,
@@ -791,6 +1128,21 @@ pub unsafe extern "C" fn cass_iterator_columns_from_table_meta(
     Box::into_raw(Box::new(CassIterator::CassTableMetaIterator(iterator)))
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_table_meta(
+    table_meta: *const CassTableMeta,
+) -> *mut CassIterator {
+    let metadata = ptr_to_ref(table_meta);
+
+    let iterator = CassTableMetaIterator {
+        value: metadata,
+        count: metadata.fields.len(),
+        position: None,
+    };
+
+    Box::into_raw(Box::new(CassIterator::CassTableMetaFieldIterator(iterator)))
+}
+
 pub unsafe extern "C" fn cass_iterator_materialized_views_from_table_meta(
     table_meta: *const CassTableMeta,
 ) -> *mut CassIterator {
@@ -802,7 +1154,7 @@ pub unsafe extern "C" fn cass_iterator_materialized_views_from_table_meta(
         position: None,
     };
 
-    Box::into_raw(Box::new(CassIterator::CassTableMetaIterator(iterator)))
+    Box::into_raw(Box::new(CassIterator::CassTableMetaViewIterator(iterator)))
 }
 
 pub unsafe extern "C" fn cass_iterator_columns_from_materialized_view_meta(
@@ -819,6 +1171,21 @@ pub unsafe extern "C" fn cass_iterator_columns_from_materialized_view_meta(
     Box::into_raw(Box::new(CassIterator::CassViewMetaIterator(iterator)))
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_materialized_view_meta(
+    view_meta: *const CassMaterializedViewMeta,
+) -> *mut CassIterator {
+    let metadata = ptr_to_ref(view_meta);
+
+    let iterator = CassViewMetaIterator {
+        value: metadata,
+        count: metadata.view_metadata.fields.len(),
+        position: None,
+    };
+
+    Box::into_raw(Box::new(CassIterator::CassViewMetaFieldIterator(iterator)))
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_result_free(result_raw: *const CassResult) {
     free_arced(result_raw);
@@ -838,7 +1205,7 @@ pub unsafe extern "C" fn cass_row_get_column(
     let row: &CassRow = ptr_to_ref(row_raw);
 
     let index_usize: usize = index.try_into().unwrap();
-    let column_value = match row.columns.get(index_usize) {
+    let column_value = match row.columns().get(index_usize) {
         Some(val) => val,
         None => return std::ptr::null(),
     };
@@ -883,7 +1250,7 @@ pub unsafe extern "C" fn cass_row_get_column_by_name_n(
                 || !is_case_sensitive && spec.name.eq_ignore_ascii_case(name_str)
         })
         .map(|(index, _)| {
-            return match row_from_raw.columns.get(index) {
+            return match row_from_raw.columns().get(index) {
                 Some(value) => value as *const CassValue,
                 None => std::ptr::null(),
             };
@@ -891,6 +1258,26 @@ pub unsafe extern "C" fn cass_row_get_column_by_name_n(
         .unwrap_or(std::ptr::null());
 }
 
+unsafe fn result_column_str(
+    result: *const CassResult,
+    index: size_t,
+    get_str: impl FnOnce(&ColumnSpec) -> &str,
+    output: *mut *const c_char,
+    output_length: *mut size_t,
+) -> CassError {
+    let result_from_raw = ptr_to_ref(result);
+    let index_usize: usize = index.try_into().unwrap();
+
+    let column_spec: &ColumnSpec = match result_from_raw.metadata.col_specs.get(index_usize) {
+        Some(column_spec) => column_spec,
+        None => return CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS,
+    };
+
+    write_str_to_c(get_str(column_spec), output, output_length);
+
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_result_column_name(
     result: *const CassResult,
@@ -898,19 +1285,65 @@ pub unsafe extern "C" fn cass_result_column_name(
     name: *mut *const c_char,
     name_length: *mut size_t,
 ) -> CassError {
-    let result_from_raw = ptr_to_ref(result);
-    let index_usize: usize = index.try_into().unwrap();
+    result_column_str(result, index, |spec| spec.name.as_str(), name, name_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_keyspace(
+    result: *const CassResult,
+    index: size_t,
+    keyspace: *mut *const c_char,
+    keyspace_length: *mut size_t,
+) -> CassError {
+    result_column_str(
+        result,
+        index,
+        |spec| spec.table_spec.ks_name.as_str(),
+        keyspace,
+        keyspace_length,
+    )
+}
 
-    if index_usize >= result_from_raw.metadata.col_specs.len() {
-        return CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS;
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_table(
+    result: *const CassResult,
+    index: size_t,
+    table: *mut *const c_char,
+    table_length: *mut size_t,
+) -> CassError {
+    result_column_str(
+        result,
+        index,
+        |spec| spec.table_spec.table_name.as_str(),
+        table,
+        table_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_type(
+    result: *const CassResult,
+    index: size_t,
+) -> CassValueType {
+    let data_type = cass_result_column_data_type(result, index);
+    if data_type.is_null() {
+        return CassValueType::CASS_VALUE_TYPE_UNKNOWN;
     }
 
-    let column_spec: &ColumnSpec = result_from_raw.metadata.col_specs.get(index_usize).unwrap();
-    let column_name = column_spec.name.as_str();
+    cass_data_type_type(data_type)
+}
 
-    write_str_to_c(column_name, name, name_length);
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_data_type(
+    result: *const CassResult,
+    index: size_t,
+) -> *const CassDataType {
+    let result_from_raw = ptr_to_ref(result);
 
-    CassError::CASS_OK
+    match result_from_raw.metadata.col_data_types.get(index as usize) {
+        Some(data_type) => Arc::as_ptr(data_type),
+        None => std::ptr::null(),
+    }
 }
 
 #[no_mangle]
@@ -968,6 +1401,7 @@ pub unsafe extern "C" fn cass_value_get_bool(
     let out: &mut cass_bool_t = ptr_to_ref_mut(output);
     match val.value {
         Some(Value::RegularValue(CqlValue::Boolean(b))) => *out = b as cass_bool_t,
+        // As in Cpp Driver, getters are strict - numbers are not booleans and vice versa.
         Some(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
         None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
     };
@@ -1056,10 +1490,45 @@ pub unsafe extern "C" fn cass_value_get_int64(
         Some(Value::RegularValue(CqlValue::Timestamp(d))) => {
             *out = d.num_milliseconds() as cass_int64_t
         }
+        // As a convenience, varints are returned if they fit in int64. Bytes of larger ones
+        // can be read with `cass_value_get_bytes`.
+        Some(Value::RegularValue(CqlValue::Varint(ref v))) => match v.try_into() {
+            Ok(v) => *out = v,
+            Err(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+        },
+        Some(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+        None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
+    };
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_get_time_components(
+    value: *const CassValue,
+    hours: *mut cass_int32_t,
+    minutes: *mut cass_int32_t,
+    seconds: *mut cass_int32_t,
+    nanos: *mut cass_int32_t,
+) -> CassError {
+    let val: &CassValue = ptr_to_ref(value);
+    // Time is stored as nanoseconds since midnight.
+    let time_nanos = match val.value {
+        Some(Value::RegularValue(CqlValue::Time(d))) => match d.num_nanoseconds() {
+            Some(nanos) => nanos,
+            None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
+        },
         Some(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
         None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
     };
 
+    const NANOS_IN_SECOND: i64 = 1_000_000_000;
+    let total_seconds = time_nanos / NANOS_IN_SECOND;
+    *ptr_to_ref_mut(hours) = (total_seconds / 3600) as cass_int32_t;
+    *ptr_to_ref_mut(minutes) = (total_seconds / 60 % 60) as cass_int32_t;
+    *ptr_to_ref_mut(seconds) = (total_seconds % 60) as cass_int32_t;
+    *ptr_to_ref_mut(nanos) = (time_nanos % NANOS_IN_SECOND) as cass_int32_t;
+
     CassError::CASS_OK
 }
 
@@ -1108,10 +1577,11 @@ pub unsafe extern "C" fn cass_value_get_string(
         // on any type and get internal represenation. I don't see how to do it easily in
         // a compatible way in rust, so let's do something sensible - only return result
         // for string values.
-        Some(Value::RegularValue(CqlValue::Ascii(s))) => {
-            write_str_to_c(s.as_str(), output, output_size)
-        }
-        Some(Value::RegularValue(CqlValue::Text(s))) => {
+        // `varchar` is an alias of `text`, so it is deserialized as CqlValue::Text.
+        // Both variants hold a Rust String - Rust Driver fails the deserialization
+        // of invalid UTF-8, so only valid strings are written here.
+        Some(Value::RegularValue(CqlValue::Ascii(s)))
+        | Some(Value::RegularValue(CqlValue::Text(s))) => {
             write_str_to_c(s.as_str(), output, output_size)
         }
         Some(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
@@ -1133,12 +1603,82 @@ pub unsafe extern "C" fn cass_value_get_bytes(
 
     let value_from_raw: &CassValue = ptr_to_ref(value);
 
-    // FIXME: This should be implemented for all CQL types
-    // Note: currently rust driver does not allow to get raw bytes of the CQL value.
-    match &value_from_raw.value {
-        Some(Value::RegularValue(CqlValue::Blob(bytes))) => {
-            *output = bytes.as_ptr() as *const cass_byte_t;
-            *output_size = bytes.len() as u64;
+    // Rust Driver does not keep raw bytes of the values, so (except for blobs)
+    // they are serialized again from the deserialized value.
+    let bytes = match &value_from_raw.value {
+        Some(Value::RegularValue(CqlValue::Blob(bytes))) => bytes,
+        Some(_) => {
+            let raw_bytes = value_from_raw.raw_bytes.get_or_init(|| {
+                let mut buf = Vec::new();
+                serialize_value(value_from_raw.value.as_ref(), &mut buf).ok()?;
+                // Skip the length of the value.
+                Some(buf.split_off(4))
+            });
+            match raw_bytes {
+                Some(bytes) => bytes,
+                None => return CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
+            }
+        }
+        None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
+    };
+
+    // The bytes are owned by the value, so repeated calls return the same pointer.
+    *output = bytes.as_ptr() as *const cass_byte_t;
+    *output_size = bytes.len() as u64;
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_bytes_size(value: *const CassValue) -> size_t {
+    // The size is taken from `cass_value_get_bytes`, so both always agree.
+    let mut bytes: *const cass_byte_t = std::ptr::null();
+    let mut bytes_size: size_t = 0;
+    match cass_value_get_bytes(value, &mut bytes, &mut bytes_size) {
+        CassError::CASS_OK => bytes_size,
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_get_decimal(
+    value: *const CassValue,
+    varint: *mut *const cass_byte_t,
+    varint_size: *mut size_t,
+    scale: *mut cass_int32_t,
+) -> CassError {
+    let val: &CassValue = ptr_to_ref(value);
+
+    match &val.value {
+        Some(Value::DecimalValue {
+            varint: decimal_varint,
+            scale: decimal_scale,
+        }) => {
+            *varint = decimal_varint.as_ptr();
+            *varint_size = decimal_varint.len() as size_t;
+            *scale = *decimal_scale;
+        }
+        Some(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+        None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
+    }
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_get_duration(
+    value: *const CassValue,
+    months: *mut cass_int32_t,
+    days: *mut cass_int32_t,
+    nanos: *mut cass_int64_t,
+) -> CassError {
+    let val: &CassValue = ptr_to_ref(value);
+
+    match &val.value {
+        Some(Value::RegularValue(CqlValue::Duration(duration))) => {
+            *months = duration.months;
+            *days = duration.days;
+            *nanos = duration.nanoseconds;
         }
         Some(_) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
         None => return CassError::CASS_ERROR_LIB_NULL_VALUE,
@@ -1153,6 +1693,17 @@ pub unsafe extern "C" fn cass_value_is_null(value: *const CassValue) -> cass_boo
     val.value.is_none() as cass_bool_t
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_equals(
+    value_a: *const CassValue,
+    value_b: *const CassValue,
+) -> cass_bool_t {
+    let value_a = ptr_to_ref(value_a);
+    let value_b = ptr_to_ref(value_b);
+
+    (value_a == value_b) as cass_bool_t
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_value_is_collection(value: *const CassValue) -> cass_bool_t {
     let val = ptr_to_ref(value);
@@ -1165,12 +1716,25 @@ pub unsafe extern "C" fn cass_value_is_collection(value: *const CassValue) -> ca
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_is_duration(value: *const CassValue) -> cass_bool_t {
+    let val = ptr_to_ref(value);
+
+    // Based on the type, so null durations are reported as durations too.
+    matches!(
+        &*val.value_type,
+        CassDataType::Value(CassValueType::CASS_VALUE_TYPE_DURATION)
+    ) as cass_bool_t
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_value_item_count(collection: *const CassValue) -> size_t {
     let val = ptr_to_ref(collection);
 
     match &val.value {
         Some(Value::CollectionValue(Collection::List(list))) => list.len() as size_t,
+        // For maps, it is the number of key-value entries (not 2 * entries),
+        // which is also the number of steps taken by a map iterator.
         Some(Value::CollectionValue(Collection::Map(map))) => map.len() as size_t,
         Some(Value::CollectionValue(Collection::Set(set))) => set.len() as size_t,
         Some(Value::CollectionValue(Collection::Tuple(tuple))) => tuple.len() as size_t,
@@ -1211,11 +1775,40 @@ pub unsafe extern "C" fn cass_value_secondary_sub_type(
 pub unsafe extern "C" fn cass_result_row_count(result_raw: *const CassResult) -> size_t {
     let result = ptr_to_ref(result_raw);
 
-    if result.rows.as_ref().is_none() {
-        return 0;
-    }
+    // Results of non-SELECT statements (e.g. INSERT or batch) do not contain rows.
+    result.rows.as_ref().map_or(0, |rows| rows.len() as size_t)
+}
 
-    result.rows.as_ref().unwrap().len() as size_t
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_has_rows(result_raw: *const CassResult) -> cass_bool_t {
+    let result = ptr_to_ref(result_raw);
+
+    // Rows result of a SELECT has rows even if there are none of them in the page.
+    result.rows.is_some() as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_was_applied(result_raw: *const CassResult) -> cass_bool_t {
+    let result = ptr_to_ref(result_raw);
+
+    // Results of non-conditional statements have no `[applied]` column,
+    // so they are considered applied once they succeed.
+    let applied_index = match result
+        .metadata
+        .col_specs
+        .iter()
+        .position(|spec| spec.name == "[applied]")
+    {
+        Some(index) => index,
+        None => return true as cass_bool_t,
+    };
+
+    let applied = result
+        .first_row()
+        .and_then(|row| row.columns().get(applied_index))
+        .and_then(|column| column.value.as_ref());
+
+    matches!(applied, Some(Value::RegularValue(CqlValue::Boolean(true)))) as cass_bool_t
 }
 
 #[no_mangle]
@@ -1229,11 +1822,9 @@ pub unsafe extern "C" fn cass_result_column_count(result_raw: *const CassResult)
 pub unsafe extern "C" fn cass_result_first_row(result_raw: *const CassResult) -> *const CassRow {
     let result = ptr_to_ref(result_raw);
 
-    if result.rows.is_some() || result.rows.as_ref().unwrap().is_empty() {
-        return result.rows.as_ref().unwrap().first().unwrap();
-    }
-
-    std::ptr::null()
+    result
+        .first_row()
+        .map_or(std::ptr::null(), |row| row as *const CassRow)
 }
 
 #[no_mangle]
@@ -1270,9 +1861,11 @@ pub unsafe extern "C" fn cass_statement_set_paging_state_token(
 ) -> CassError {
     let statement_from_raw = ptr_to_ref_mut(statement);
 
-    if paging_state.is_null() {
+    if paging_state.is_null() || paging_state_size == 0 {
+        // Null or empty paging state token clears the paging state, so the statement
+        // will be executed starting from the first page.
         statement_from_raw.paging_state = None;
-        return CassError::CASS_ERROR_LIB_NULL_VALUE;
+        return CassError::CASS_OK;
     }
 
     let paging_state_usize: usize = paging_state_size.try_into().unwrap();
@@ -1287,14 +1880,66 @@ pub unsafe extern "C" fn cass_statement_set_paging_state_token(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_paging_state_token_base64(
+    result: *const CassResult,
+    paging_state: *mut *const c_char,
+    paging_state_size: *mut size_t,
+) -> CassError {
+    if cass_result_has_more_pages(result) == cass_false {
+        return CassError::CASS_ERROR_LIB_NO_PAGING_STATE;
+    }
+
+    let result_from_raw = ptr_to_ref(result);
+
+    let metadata = &result_from_raw.metadata;
+    match &metadata.paging_state {
+        Some(result_paging_state) => {
+            let encoded = metadata
+                .paging_state_base64
+                .get_or_init(|| base64::encode(result_paging_state));
+            *paging_state_size = encoded.len() as u64;
+            *paging_state = encoded.as_ptr() as *const c_char;
+        }
+        None => {
+            *paging_state_size = 0;
+            *paging_state = std::ptr::null();
+        }
+    }
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_paging_state_token_base64(
+    statement: *mut CassStatement,
+    paging_state: *const c_char,
+    paging_state_size: size_t,
+) -> CassError {
+    if paging_state.is_null() {
+        return cass_statement_set_paging_state_token(statement, paging_state, paging_state_size);
+    }
+
+    let paging_state_usize: usize = paging_state_size.try_into().unwrap();
+    let encoded = slice::from_raw_parts(paging_state as *const u8, paging_state_usize);
+    // Tokens are encoded with padding, so their length is always a multiple of 4.
+    if encoded.len() % 4 != 0 {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    let decoded = match base64::decode(encoded) {
+        Ok(decoded) => decoded,
+        Err(_) => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+
+    cass_statement_set_paging_state_token(
+        statement,
+        decoded.as_ptr() as *const c_char,
+        decoded.len() as size_t,
+    )
+}
+
 // CassResult functions:
 /*
-extern "C" {
-    pub fn cass_statement_set_paging_state(
-        statement: *mut CassStatement,
-        result: *const CassResult,
-    ) -> CassError;
-}
 extern "C" {
     pub fn cass_result_row_count(result: *const CassResult) -> size_t;
 }
@@ -1309,15 +1954,6 @@ extern "C" {
         name_length: *mut size_t,
     ) -> CassError;
 }
-extern "C" {
-    pub fn cass_result_column_type(result: *const CassResult, index: size_t) -> CassValueType;
-}
-extern "C" {
-    pub fn cass_result_column_data_type(
-        result: *const CassResult,
-        index: size_t,
-    ) -> *const CassDataType;
-}
 extern "C" {
     pub fn cass_result_first_row(result: *const CassResult) -> *const CassRow;
 }
@@ -1335,10 +1971,6 @@ extern "C" {
 
 // CassIterator functions:
 /*
-extern "C" {
-    pub fn cass_iterator_type(iterator: *mut CassIterator) -> CassIteratorType;
-}
-
 extern "C" {
     pub fn cass_iterator_from_row(row: *const CassRow) -> *mut CassIterator;
 }
@@ -1384,11 +2016,6 @@ extern "C" {
         keyspace_meta: *const CassKeyspaceMeta,
     ) -> *mut CassIterator;
 }
-extern "C" {
-    pub fn cass_iterator_fields_from_keyspace_meta(
-        keyspace_meta: *const CassKeyspaceMeta,
-    ) -> *mut CassIterator;
-}
 extern "C" {
     pub fn cass_iterator_columns_from_table_meta(
         table_meta: *const CassTableMeta,
@@ -1404,21 +2031,11 @@ extern "C" {
         table_meta: *const CassTableMeta,
     ) -> *mut CassIterator;
 }
-extern "C" {
-    pub fn cass_iterator_fields_from_table_meta(
-        table_meta: *const CassTableMeta,
-    ) -> *mut CassIterator;
-}
 extern "C" {
     pub fn cass_iterator_columns_from_materialized_view_meta(
         view_meta: *const CassMaterializedViewMeta,
     ) -> *mut CassIterator;
 }
-extern "C" {
-    pub fn cass_iterator_fields_from_materialized_view_meta(
-        view_meta: *const CassMaterializedViewMeta,
-    ) -> *mut CassIterator;
-}
 extern "C" {
     pub fn cass_iterator_fields_from_column_meta(
         column_meta: *const CassColumnMeta,
@@ -1495,16 +2112,6 @@ extern "C" {
 extern "C" {
     pub fn cass_iterator_get_index_meta(iterator: *const CassIterator) -> *const CassIndexMeta;
 }
-extern "C" {
-    pub fn cass_iterator_get_meta_field_name(
-        iterator: *const CassIterator,
-        name: *mut *const ::std::os::raw::c_char,
-        name_length: *mut size_t,
-    ) -> CassError;
-}
-extern "C" {
-    pub fn cass_iterator_get_meta_field_value(iterator: *const CassIterator) -> *const CassValue;
-}
 */
 
 // CassRow functions:
@@ -1526,29 +2133,6 @@ extern "C" {
 
 // CassValue functions:
 /*
-#[no_mangle]
-pub unsafe extern "C" fn cass_value_get_bytes(
-    value: *const CassValue,
-    output: *mut *const cass_byte_t,
-    output_size: *mut size_t,
-) -> CassError {
-}
-#[no_mangle]
-pub unsafe extern "C" fn cass_value_get_decimal(
-    value: *const CassValue,
-    varint: *mut *const cass_byte_t,
-    varint_size: *mut size_t,
-    scale: *mut cass_int32_t,
-) -> CassError {
-}
-#[no_mangle]
-pub unsafe extern "C" fn cass_value_get_duration(
-    value: *const CassValue,
-    months: *mut cass_int32_t,
-    days: *mut cass_int32_t,
-    nanos: *mut cass_int64_t,
-) -> CassError {
-}
 extern "C" {
     pub fn cass_value_data_type(value: *const CassValue) -> *const CassDataType;
 }
@@ -1558,9 +2142,6 @@ extern "C" {
 extern "C" {
     pub fn cass_value_is_collection(value: *const CassValue) -> cass_bool_t;
 }
-extern "C" {
-    pub fn cass_value_is_duration(value: *const CassValue) -> cass_bool_t;
-}
 extern "C" {
     pub fn cass_value_item_count(collection: *const CassValue) -> size_t;
 }
@@ -0,0 +1,199 @@
+use crate::metadata::CassMetaFields;
+use crate::query_result::CassResultData;
+use crate::session::{create_cass_result_data, create_cass_row_columns, CassSession};
+use scylla::frame::response::result::{CqlValue, Row};
+use scylla::{QueryResult, Session};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+// Rust Driver does not expose the rows of `system_schema` tables, nor notify about
+// refreshes of its metadata, so the metadata known to the session is polled and
+// the rows are queried again when it has changed since the previous poll.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+// The same as the default request timeout of Cpp Driver.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(12000);
+
+// Rows of a `system_schema` table, keyed by the values of its text key columns
+// (e.g. `keyspace_name` and `table_name`).
+struct SchemaRows {
+    metadata: Arc<CassResultData>,
+    rows: HashMap<Vec<String>, Row>,
+}
+
+impl SchemaRows {
+    fn new(result: QueryResult, key_column_names: &[&str]) -> SchemaRows {
+        let (metadata, rows) = create_cass_result_data(result);
+        let key_column_indexes: Vec<Option<usize>> = key_column_names
+            .iter()
+            .map(|name| {
+                metadata
+                    .col_specs
+                    .iter()
+                    .position(|col_spec| col_spec.name == *name)
+            })
+            .collect();
+
+        let rows = rows
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|row| {
+                let key = key_column_indexes
+                    .iter()
+                    .map(|index| match row.columns.get((*index)?)? {
+                        Some(CqlValue::Text(text)) => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<String>>>()?;
+                Some((key, row))
+            })
+            .collect();
+
+        SchemaRows { metadata, rows }
+    }
+
+    // Values are created on every call, as each schema metadata owns its fields.
+    fn fields(&self, key: &[String]) -> Option<CassMetaFields> {
+        let row = self.rows.get(key)?;
+        let columns = create_cass_row_columns(
+            Row {
+                columns: row.columns.clone(),
+            },
+            &self.metadata,
+        );
+
+        Some(
+            self.metadata
+                .col_specs
+                .iter()
+                .map(|col_spec| col_spec.name.clone())
+                .zip(columns)
+                .collect(),
+        )
+    }
+}
+
+pub struct SchemaFields {
+    keyspaces: Option<SchemaRows>,
+    tables: Option<SchemaRows>,
+}
+
+impl SchemaFields {
+    pub async fn query(session: &Session) -> SchemaFields {
+        let (keyspaces, tables) = tokio::join!(
+            query_schema_rows(session, "keyspaces", &["keyspace_name"]),
+            query_schema_rows(session, "tables", &["keyspace_name", "table_name"]),
+        );
+
+        SchemaFields { keyspaces, tables }
+    }
+
+    // Rows which could not be queried are kept from the previous query.
+    fn update(&mut self, schema_fields: SchemaFields) {
+        if schema_fields.keyspaces.is_some() {
+            self.keyspaces = schema_fields.keyspaces;
+        }
+        if schema_fields.tables.is_some() {
+            self.tables = schema_fields.tables;
+        }
+    }
+
+    pub fn keyspace_fields(&self, keyspace_name: &str) -> Option<CassMetaFields> {
+        self.keyspaces.as_ref()?.fields(&[keyspace_name.to_owned()])
+    }
+
+    pub fn table_fields(&self, keyspace_name: &str, table_name: &str) -> Option<CassMetaFields> {
+        self.tables
+            .as_ref()?
+            .fields(&[keyspace_name.to_owned(), table_name.to_owned()])
+    }
+}
+
+async fn query_schema_rows(
+    session: &Session,
+    table_name: &str,
+    key_column_names: &[&str],
+) -> Option<SchemaRows> {
+    let query = format!("SELECT * FROM system_schema.{}", table_name);
+    match tokio::time::timeout(QUERY_TIMEOUT, session.query(query, &[])).await {
+        Ok(Ok(result)) => Some(SchemaRows::new(result, key_column_names)),
+        Ok(Err(err)) => {
+            warn!("Unable to query system_schema.{}: {}", table_name, err);
+            None
+        }
+        Err(_timeout_err) => {
+            warn!("Query of system_schema.{} timed out", table_name);
+            None
+        }
+    }
+}
+
+pub struct SchemaFieldsTask {
+    schema_fields: Arc<Mutex<SchemaFields>>,
+    refresh_task: JoinHandle<()>,
+}
+
+impl SchemaFieldsTask {
+    // Must be called within the Tokio runtime, as it spawns the refreshing task.
+    // The fields queried when the session is connected are refreshed only
+    // after the metadata of the session has changed.
+    pub fn new(schema_fields: SchemaFields, session: Weak<CassSession>) -> SchemaFieldsTask {
+        let schema_fields = Arc::new(Mutex::new(schema_fields));
+        let schema_fields_clone = schema_fields.clone();
+
+        let refresh_task = tokio::spawn(async move {
+            let mut previous_cluster_data = None;
+            let mut interval = tokio::time::interval(POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+
+                let session = match session.upgrade() {
+                    Some(session) => session,
+                    None => return,
+                };
+                let session_guard = session.read().await;
+                let session_inner = match session_guard.as_ref() {
+                    Some(session_inner) => session_inner,
+                    None => return,
+                };
+
+                let cluster_data = session_inner.session.get_cluster_data();
+                let changed = match &previous_cluster_data {
+                    Some(previous_cluster_data) => {
+                        !Arc::ptr_eq(previous_cluster_data, &cluster_data)
+                    }
+                    None => false,
+                };
+                previous_cluster_data = Some(cluster_data);
+
+                if changed {
+                    let refreshed = SchemaFields::query(&session_inner.session).await;
+                    schema_fields_clone
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .update(refreshed);
+                }
+            }
+        });
+
+        SchemaFieldsTask {
+            schema_fields,
+            refresh_task,
+        }
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, SchemaFields> {
+        self.schema_fields
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for SchemaFieldsTask {
+    fn drop(&mut self) {
+        // The task is stopped when the session is closed or freed.
+        self.refresh_task.abort();
+    }
+}
@@ -4,30 +4,97 @@ use crate::cass_error::*;
 use crate::cass_types::{get_column_type, CassDataType, CassDataTypeArc, UDTDataType};
 use crate::cluster::build_session_builder;
 use crate::cluster::CassCluster;
+use crate::exec_profile::CassExecProfile;
 use crate::future::{CassFuture, CassResultValue};
+use crate::host_listener::HostListenerTask;
 use crate::logging::init_logging;
-use crate::metadata::create_table_metadata;
+use crate::metadata::{create_keyspace_fields, create_table_metadata, create_view_fields};
 use crate::metadata::{CassKeyspaceMeta, CassMaterializedViewMeta, CassSchemaMeta};
-use crate::query_result::Value::{CollectionValue, RegularValue};
+use crate::metrics::{LatencyHistogram, MetricsSnapshot};
+use crate::query_result::Value::{CollectionValue, DecimalValue, RegularValue};
 use crate::query_result::{
     CassResult, CassResultData, CassResult_, CassRow, CassValue, Collection, Value,
 };
+use crate::schema_fields::{SchemaFields, SchemaFieldsTask};
 use crate::statement::CassStatement;
 use crate::statement::Statement;
 use crate::types::{cass_uint64_t, size_t};
+use crate::uuid::{gen_random_uuid, CassUuid};
 use scylla::frame::response::result::{CqlValue, Row};
-use scylla::frame::types::Consistency;
+use scylla::frame::types::{Consistency, SerialConsistency};
+use scylla::frame::value::MaybeUnset;
 use scylla::query::Query;
+use scylla::statement::prepared_statement::PreparedStatement;
 use scylla::transport::errors::QueryError;
 use scylla::{QueryResult, Session};
 use std::collections::HashMap;
 use std::future::Future;
 use std::os::raw::c_char;
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::RwLock;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::{RwLock, Semaphore, SemaphorePermit};
+use uuid::Uuid;
+
+pub struct CassSessionInner {
+    pub session: Session,
+    exec_profile_map: HashMap<String, CassExecProfile>,
+    client_id: CassUuid,
+    request_timeout_ms: Option<cass_uint64_t>,
+    consistency: Option<Consistency>,
+    serial_consistency: Option<SerialConsistency>,
+    request_limiter: Option<Semaphore>,
+    pub latencies: Arc<LatencyHistogram>,
+    pub metrics_snapshot: Option<MetricsSnapshot>,
+    _host_listener_task: Option<HostListenerTask>,
+    schema_fields: SchemaFieldsTask,
+}
+
+impl CassSessionInner {
+    // Used by all requests, so they fail the same way when the session is not connected.
+    fn get_connected(
+        session_opt: &Option<CassSessionInner>,
+    ) -> Result<&CassSessionInner, (CassError, String)> {
+        session_opt.as_ref().ok_or_else(|| {
+            (
+                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+                "Session is not connected".msg(),
+            )
+        })
+    }
+
+    // Waits until the request may be sent, if the number of concurrent requests is limited.
+    // The request is counted as in flight as long as the permit is held.
+    async fn acquire_request_permit(&self) -> Option<SemaphorePermit<'_>> {
+        match &self.request_limiter {
+            Some(limiter) => limiter.acquire().await.ok(),
+            None => None,
+        }
+    }
+
+    pub fn has_exec_profile(&self, name: &str) -> bool {
+        self.exec_profile_map.contains_key(name)
+    }
 
-pub type CassSession = RwLock<Option<Session>>;
+    // Profiles are resolved by name at execution time, so a statement may refer
+    // to a profile which was not registered in the cluster the session was connected with.
+    fn get_exec_profile(
+        &self,
+        name: &Option<String>,
+    ) -> Result<Option<&CassExecProfile>, (CassError, String)> {
+        match name {
+            Some(name) => match self.exec_profile_map.get(name) {
+                Some(profile) => Ok(Some(profile)),
+                None => Err((
+                    CassError::CASS_ERROR_LIB_EXECUTION_PROFILE_INVALID,
+                    format!("Execution profile '{}' does not exist", name),
+                )),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+pub type CassSession = RwLock<Option<CassSessionInner>>;
 type CassSession_ = Arc<CassSession>;
 
 #[no_mangle]
@@ -44,6 +111,8 @@ pub unsafe extern "C" fn cass_session_connect(
     cluster_raw: *const CassCluster,
 ) -> *const CassFuture {
     let session_opt = ptr_to_ref(session_raw);
+    // Host listener refers to the session weakly, so it does not keep a freed session alive.
+    let session_weak = Arc::downgrade(&clone_arced(session_raw));
     let cluster: CassCluster = (*ptr_to_ref(cluster_raw)).clone();
 
     CassFuture::make_raw(async move {
@@ -57,12 +126,58 @@ pub unsafe extern "C" fn cass_session_connect(
             ));
         }
 
-        let session = build_session_builder(&cluster)
-            .build()
-            .await
-            .map_err(|err| (CassError::from(&err), err.msg()))?;
+        let session_builder = build_session_builder(&cluster);
+        let session_future = session_builder.build();
+        let session_res = match cluster.session_connect_deadline() {
+            Some(deadline) => match tokio::time::timeout(deadline, session_future).await {
+                Ok(res) => res,
+                Err(_timeout_err) => {
+                    return Err((
+                        CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
+                        "Session connect deadline exceeded".msg(),
+                    ))
+                }
+            },
+            None => session_future.await,
+        };
 
-        *session_guard = Some(session);
+        let session = session_res.map_err(|err| {
+            let cass_error = CassError::from(&err);
+            // The same message as in Cpp Driver, followed by the cause.
+            let message = if cass_error == CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE {
+                format!("Unable to connect to any contact points: {}", err.msg())
+            } else {
+                err.msg()
+            };
+            (cass_error, message)
+        })?;
+
+        let latencies = Arc::new(LatencyHistogram::default());
+        let metrics_snapshot = cluster
+            .histogram_refresh_interval()
+            .map(|interval| MetricsSnapshot::new(latencies.clone(), interval));
+        let schema_fields =
+            SchemaFieldsTask::new(SchemaFields::query(&session).await, session_weak.clone());
+        let host_listener_task = cluster
+            .host_listener()
+            .map(|listener| HostListenerTask::new(listener, session_weak));
+
+        *session_guard = Some(CassSessionInner {
+            session,
+            exec_profile_map: cluster.execution_profile_map().clone(),
+            // As in Cpp Driver, random (version 4) UUID is used if client id was not set.
+            client_id: cluster.client_id().unwrap_or_else(gen_random_uuid),
+            request_timeout_ms: cluster.request_timeout_ms(),
+            consistency: cluster.consistency(),
+            serial_consistency: cluster.serial_consistency(),
+            request_limiter: cluster
+                .max_concurrent_requests()
+                .map(|max_requests| Semaphore::new(max_requests as usize)),
+            latencies,
+            metrics_snapshot,
+            _host_listener_task: host_listener_task,
+            schema_fields,
+        });
         Ok(CassResultValue::Empty)
     })
 }
@@ -76,49 +191,106 @@ pub unsafe extern "C" fn cass_session_execute_batch(
     let batch_from_raw = ptr_to_ref(batch_raw);
     let state = batch_from_raw.state.clone();
     let request_timeout_ms = batch_from_raw.batch_request_timeout_ms;
+    let exec_profile_name = batch_from_raw.exec_profile.clone();
+    let consistency = batch_from_raw.consistency;
+    let serial_consistency = batch_from_raw.serial_consistency;
 
     let future = async move {
         let session_guard = session_opt.read().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-                "Session is not connected".msg(),
-            ));
+        let session_inner = CassSessionInner::get_connected(&session_guard)?;
+        let exec_profile = session_inner.get_exec_profile(&exec_profile_name)?;
+        let session = &session_inner.session;
+
+        // Consistencies set for the batch take precedence over the ones of the profile,
+        // which take precedence over the ones of the cluster.
+        let consistency = consistency
+            .or_else(|| exec_profile.and_then(|p| p.consistency))
+            .or(session_inner.consistency);
+        let serial_consistency = serial_consistency
+            .or_else(|| exec_profile.and_then(|p| p.serial_consistency))
+            .or(session_inner.serial_consistency);
+        // Consistencies are set on a copy of the batch made for this request (as for
+        // statements), so the bound values shared with the user's batch are not copied.
+        let mut batch = state.batch.clone();
+        if let Some(consistency) = consistency {
+            batch.set_consistency(consistency);
+        }
+        if let Some(serial_consistency) = serial_consistency {
+            batch.set_serial_consistency(Some(serial_consistency));
         }
-        let session = session_guard.as_ref().unwrap();
 
-        let query_res = session.batch(&state.batch, &state.bound_values).await;
+        // Request timeout of the batch takes precedence over the one of the profile,
+        // which takes precedence over the one of the cluster.
+        let request_timeout_ms = request_timeout_ms
+            .or_else(|| exec_profile.and_then(|p| p.request_timeout_ms))
+            .or(session_inner.request_timeout_ms);
+
+        let _permit = session_inner.acquire_request_permit().await;
+        let request_start = Instant::now();
+        let query_res = request_with_timeout(
+            request_timeout_ms,
+            session.batch(&batch, &state.bound_values),
+        )
+        .await;
+        session_inner.latencies.record(request_start.elapsed());
         match query_res {
-            Ok(_result) => Ok(CassResultValue::QueryResult(Arc::new(CassResult {
+            Ok(result) => Ok(CassResultValue::QueryResult(Arc::new(CassResult {
                 rows: None,
                 metadata: Arc::new(CassResultData {
                     paging_state: None,
+                    paging_state_base64: OnceLock::new(),
                     col_specs: vec![],
-                    tracing_id: None,
+                    col_data_types: vec![],
+                    // Tracing id is propagated the same way as for statements,
+                    // so it can be read with cass_future_tracing_id.
+                    tracing_id: result.tracing_id,
                 }),
             }))),
             Err(err) => Ok(CassResultValue::QueryError(Arc::new(err))),
         }
     };
 
+    CassFuture::make_raw(future)
+}
+
+async fn request_with_timeout<T>(
+    request_timeout_ms: Option<cass_uint64_t>,
+    future: impl Future<Output = Result<T, QueryError>>,
+) -> Result<T, QueryError> {
     match request_timeout_ms {
-        Some(timeout_ms) => {
-            CassFuture::make_raw(async move { request_with_timeout(timeout_ms, future).await })
+        // As in Cpp Driver, timeout equal to 0 disables the timeout. This way the timeout
+        // of the cluster may be disabled for a single statement (or profile) too.
+        Some(timeout_ms) if timeout_ms > 0 => {
+            match tokio::time::timeout(Duration::from_millis(timeout_ms), future).await {
+                Ok(result) => result,
+                Err(_timeout_err) => Err(QueryError::TimeoutError),
+            }
         }
-        None => CassFuture::make_raw(future),
+        _ => future.await,
     }
 }
 
-async fn request_with_timeout(
-    request_timeout_ms: cass_uint64_t,
-    future: impl Future<Output = Result<CassResultValue, (CassError, String)>>,
-) -> Result<CassResultValue, (CassError, String)> {
-    match tokio::time::timeout(Duration::from_millis(request_timeout_ms), future).await {
-        Ok(result) => result,
-        Err(_timeout_err) => Ok(CassResultValue::QueryError(Arc::new(
-            QueryError::TimeoutError,
-        ))),
+// Partition key is required to execute the request, so (as in Cpp Driver) the request
+// is not sent if any of the partition key columns of a prepared statement is unset.
+fn check_partition_key_set(
+    prepared: &PreparedStatement,
+    bound_values: &[MaybeUnset<Option<CqlValue>>],
+) -> Result<(), (CassError, String)> {
+    let metadata = prepared.get_prepared_metadata();
+    for pk_index in &metadata.pk_indexes {
+        let index = pk_index.index as usize;
+        if let Some(MaybeUnset::Unset) = bound_values.get(index) {
+            return Err((
+                CassError::CASS_ERROR_LIB_PARAMETER_UNSET,
+                format!(
+                    "Partition key column '{}' is unset",
+                    metadata.col_specs[index].name
+                ),
+            ));
+        }
     }
+
+    Ok(())
 }
 
 #[no_mangle]
@@ -131,98 +303,188 @@ pub unsafe extern "C" fn cass_session_execute(
     let paging_state = statement_opt.paging_state.clone();
     let bound_values = statement_opt.bound_values.clone();
     let request_timeout_ms = statement_opt.request_timeout_ms;
+    let exec_profile_name = statement_opt.exec_profile.clone();
+    let consistency = statement_opt.consistency;
+    let serial_consistency = statement_opt.serial_consistency;
+    let lazy_rows = statement_opt.lazy_rows;
 
-    let statement = statement_opt.statement.clone();
+    let mut statement = statement_opt.statement.clone();
 
     let future = async move {
         let session_guard = session_opt.read().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-                "Session is not connected".msg(),
-            ));
+        let session_inner = CassSessionInner::get_connected(&session_guard)?;
+        let exec_profile = session_inner.get_exec_profile(&exec_profile_name)?;
+        let session = &session_inner.session;
+
+        // Consistencies set for the statement take precedence over the ones of the profile,
+        // which take precedence over the ones of the cluster.
+        let consistency = consistency
+            .or_else(|| exec_profile.and_then(|p| p.consistency))
+            .or(session_inner.consistency);
+        let serial_consistency = serial_consistency
+            .or_else(|| exec_profile.and_then(|p| p.serial_consistency.map(Some)))
+            .or_else(|| session_inner.serial_consistency.map(Some));
+        if let Some(consistency) = consistency {
+            statement.set_consistency(consistency);
+        }
+        if let Some(serial_consistency) = serial_consistency {
+            statement.set_serial_consistency(serial_consistency);
+        }
+
+        // Request timeout of the statement takes precedence over the one of the profile,
+        // which takes precedence over the one of the cluster.
+        let request_timeout_ms = request_timeout_ms
+            .or_else(|| exec_profile.and_then(|p| p.request_timeout_ms))
+            .or(session_inner.request_timeout_ms);
+
+        if let Statement::Prepared(prepared) = &statement {
+            check_partition_key_set(prepared, &bound_values)?;
         }
-        let session = session_guard.as_ref().unwrap();
 
+        let _permit = session_inner.acquire_request_permit().await;
+        let request_start = Instant::now();
         let query_res: Result<QueryResult, QueryError> = match statement {
             Statement::Simple(query) => {
-                session
-                    .query_paged(query.query, bound_values, paging_state)
-                    .await
+                request_with_timeout(
+                    request_timeout_ms,
+                    session.query_paged(query.query, bound_values, paging_state),
+                )
+                .await
             }
             Statement::Prepared(prepared) => {
-                session
-                    .execute_paged(&prepared, bound_values, paging_state)
-                    .await
+                // If the statement is unknown to the node (UNPREPARED error),
+                // Rust Driver re-prepares it and retries the request transparently.
+                request_with_timeout(
+                    request_timeout_ms,
+                    session.execute_paged(&prepared, bound_values, paging_state),
+                )
+                .await
             }
         };
+        session_inner.latencies.record(request_start.elapsed());
 
         match query_res {
-            Ok(result) => {
-                let metadata = Arc::new(CassResultData {
-                    paging_state: result.paging_state,
-                    col_specs: result.col_specs,
-                    tracing_id: result.tracing_id,
-                });
-                let cass_rows = create_cass_rows_from_rows(result.rows, &metadata);
-                let cass_result: CassResult_ = Arc::new(CassResult {
-                    rows: cass_rows,
-                    metadata,
-                });
-
-                Ok(CassResultValue::QueryResult(cass_result))
-            }
+            Ok(result) => Ok(CassResultValue::QueryResult(create_cass_result(
+                result, lazy_rows,
+            ))),
             Err(err) => Ok(CassResultValue::QueryError(Arc::new(err))),
         }
     };
 
-    match request_timeout_ms {
-        Some(timeout_ms) => {
-            CassFuture::make_raw(async move { request_with_timeout(timeout_ms, future).await })
+    CassFuture::make_raw(future)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_session_get_trace(
+    session_raw: *mut CassSession,
+    tracing_id: CassUuid,
+) -> *const CassFuture {
+    let session_opt = ptr_to_ref(session_raw);
+    let tracing_id: Uuid = tracing_id.into();
+
+    let future = async move {
+        let session_guard = session_opt.read().await;
+        let session = &CassSessionInner::get_connected(&session_guard)?.session;
+
+        // Trace is written asynchronously by the coordinator, so Rust Driver's
+        // `get_tracing_info` is used to wait until the trace session is complete.
+        if let Err(err) = session.get_tracing_info(&tracing_id).await {
+            return Ok(CassResultValue::QueryError(Arc::new(err)));
         }
-        None => CassFuture::make_raw(future),
-    }
+
+        // Events are returned as rows of `system_traces.events`, so they can be read
+        // with the same functions as results of any other query.
+        let query_res = session
+            .query(
+                "SELECT * FROM system_traces.events WHERE session_id = ?",
+                (tracing_id,),
+            )
+            .await;
+
+        match query_res {
+            Ok(result) => Ok(CassResultValue::QueryResult(create_cass_result(
+                result, false,
+            ))),
+            Err(err) => Ok(CassResultValue::QueryError(Arc::new(err))),
+        }
+    };
+
+    CassFuture::make_raw(future)
+}
+
+fn create_cass_result(result: QueryResult, lazy_rows: bool) -> CassResult_ {
+    let (metadata, rows) = create_cass_result_data(result);
+    let cass_rows = create_cass_rows_from_rows(rows, &metadata, lazy_rows);
+
+    Arc::new(CassResult {
+        rows: cass_rows,
+        metadata,
+    })
+}
+
+pub fn create_cass_result_data(result: QueryResult) -> (Arc<CassResultData>, Option<Vec<Row>>) {
+    let metadata = Arc::new(CassResultData {
+        paging_state_base64: OnceLock::new(),
+        paging_state: result.paging_state,
+        // Types come from the column specs sent by the server, not from the table schema,
+        // so aliased expressions (e.g. `ttl(x) AS t`, which is an `int`) are typed correctly
+        // and are accessible by their alias.
+        col_data_types: result
+            .col_specs
+            .iter()
+            .map(|col| Arc::new(get_column_type(&col.typ)))
+            .collect(),
+        col_specs: result.col_specs,
+        tracing_id: result.tracing_id,
+    });
+
+    (metadata, result.rows)
 }
 
 fn create_cass_rows_from_rows(
     rows: Option<Vec<Row>>,
     metadata: &Arc<CassResultData>,
+    lazy_rows: bool,
 ) -> Option<Vec<CassRow>> {
     let rows = rows?;
     let cass_rows = rows
         .into_iter()
-        .map(|r| CassRow {
-            columns: create_cass_row_columns(r, metadata),
-            result_metadata: metadata.clone(),
-        })
+        .map(|r| CassRow::new(r, metadata.clone(), lazy_rows))
         .collect();
 
     Some(cass_rows)
 }
 
-fn create_cass_row_columns(row: Row, metadata: &Arc<CassResultData>) -> Vec<CassValue> {
+// Types of the values are taken from the result metadata, so computed columns
+// (e.g. `count(*)`, which is a `bigint`) are typed the same way as regular ones.
+pub fn create_cass_row_columns(row: Row, metadata: &Arc<CassResultData>) -> Vec<CassValue> {
     row.columns
         .into_iter()
-        .zip(metadata.col_specs.iter())
-        .map(|(val, col)| {
-            let column_type = Arc::new(get_column_type(&col.typ));
-            CassValue {
-                value: val.map(|col_val| get_column_value(col_val, &column_type)),
-                value_type: column_type,
-            }
+        .zip(metadata.col_data_types.iter())
+        .map(|(val, column_type)| {
+            CassValue::new(
+                val.map(|col_val| get_column_value(col_val, column_type)),
+                column_type.clone(),
+            )
         })
         .collect()
 }
 
+// Elements of collections, tuples and UDTs carry their own sub-type
+// (e.g. `int` for elements of `list<int>`), not the type of the parent value.
+fn create_cass_element_value(element: CqlValue, element_type: &CassDataTypeArc) -> CassValue {
+    CassValue::new(
+        Some(get_column_value(element, element_type)),
+        element_type.clone(),
+    )
+}
+
 fn get_column_value(column: CqlValue, column_type: &CassDataTypeArc) -> Value {
     match (column, column_type.as_ref()) {
         (CqlValue::List(list), CassDataType::List(Some(list_type))) => {
             CollectionValue(Collection::List(
                 list.into_iter()
-                    .map(|val| CassValue {
-                        value_type: list_type.clone(),
-                        value: Some(get_column_value(val, list_type)),
-                    })
+                    .map(|val| create_cass_element_value(val, list_type))
                     .collect(),
             ))
         }
@@ -231,26 +493,19 @@ fn get_column_value(column: CqlValue, column_type: &CassDataTypeArc) -> Value {
                 map.into_iter()
                     .map(|(key, val)| {
                         (
-                            CassValue {
-                                value_type: key_type.clone(),
-                                value: Some(get_column_value(key, key_type)),
-                            },
-                            CassValue {
-                                value_type: value_type.clone(),
-                                value: Some(get_column_value(val, value_type)),
-                            },
+                            create_cass_element_value(key, key_type),
+                            create_cass_element_value(val, value_type),
                         )
                     })
                     .collect(),
             ))
         }
         (CqlValue::Set(set), CassDataType::Set(Some(set_type))) => {
+            // Elements of a set are sent by the server sorted, and Rust Driver deserializes
+            // them into a Vec in that order, so iterating over a set yields sorted elements.
             CollectionValue(Collection::Set(
                 set.into_iter()
-                    .map(|val| CassValue {
-                        value_type: set_type.clone(),
-                        value: Some(get_column_value(val, set_type)),
-                    })
+                    .map(|val| create_cass_element_value(val, set_type))
                     .collect(),
             ))
         }
@@ -270,13 +525,7 @@ fn get_column_value(column: CqlValue, column_type: &CassDataTypeArc) -> Value {
                 .map(|(index, (name, val_opt))| {
                     let udt_field_type_opt = udt_type.get_field_by_index(index);
                     if let (Some(val), Some(udt_field_type)) = (val_opt, udt_field_type_opt) {
-                        return (
-                            name,
-                            Some(CassValue {
-                                value_type: udt_field_type.clone(),
-                                value: Some(get_column_value(val, udt_field_type)),
-                            }),
-                        );
+                        return (name, Some(create_cass_element_value(val, udt_field_type)));
                     }
                     (name, None)
                 })
@@ -288,16 +537,30 @@ fn get_column_value(column: CqlValue, column_type: &CassDataTypeArc) -> Value {
                     .into_iter()
                     .enumerate()
                     .map(|(index, val_opt)| {
-                        val_opt
-                            .zip(tuple_types.get(index))
-                            .map(|(val, tuple_field_type)| CassValue {
-                                value_type: tuple_field_type.clone(),
-                                value: Some(get_column_value(val, tuple_field_type)),
+                        tuple_types
+                            .get(index)
+                            .map(|tuple_field_type| match val_opt {
+                                Some(val) => create_cass_element_value(val, tuple_field_type),
+                                // Null elements are kept as null values, so iterating
+                                // over a tuple yields each of its positions.
+                                None => CassValue::new(None, tuple_field_type.clone()),
                             })
                     })
                     .collect(),
             ))
         }
+        (CqlValue::Decimal(decimal), _) => {
+            let (unscaled, scale) = decimal.as_bigint_and_exponent();
+            let mut varint = unscaled.to_signed_bytes_be();
+            // Zero is encoded as a single 0x00 byte, as in CQL protocol.
+            if varint.is_empty() {
+                varint.push(0);
+            }
+            DecimalValue {
+                varint,
+                scale: scale as i32,
+            }
+        }
         (regular_value, _) => RegularValue(regular_value),
     }
 }
@@ -320,17 +583,14 @@ pub unsafe extern "C" fn cass_session_prepare_from_existing(
         };
 
         let session_guard = session.read().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-                "Session is not connected".msg(),
-            ));
-        }
-        let session = session_guard.as_ref().unwrap();
-        let prepared = session
-            .prepare(query.query.clone())
-            .await
-            .map_err(|err| (CassError::from(&err), err.msg()))?;
+        let session_inner = CassSessionInner::get_connected(&session_guard)?;
+        let _permit = session_inner.acquire_request_permit().await;
+        let prepared = request_with_timeout(
+            session_inner.request_timeout_ms,
+            session_inner.session.prepare(query.query.clone()),
+        )
+        .await
+        .map_err(|err| (CassError::from(&err), err.msg()))?;
 
         Ok(CassResultValue::Prepared(Arc::new(prepared)))
     })
@@ -359,18 +619,16 @@ pub unsafe extern "C" fn cass_session_prepare_n(
 
     CassFuture::make_raw(async move {
         let session_guard = cass_session.read().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-                "Session is not connected".msg(),
-            ));
-        }
-        let session = session_guard.as_ref().unwrap();
+        let session_inner = CassSessionInner::get_connected(&session_guard)?;
 
-        let mut prepared = session
-            .prepare(query)
-            .await
-            .map_err(|err| (CassError::from(&err), err.msg()))?;
+        // Preparing uses the request timeout of the cluster, so a slow PREPARE does not hang.
+        let _permit = session_inner.acquire_request_permit().await;
+        let mut prepared = request_with_timeout(
+            session_inner.request_timeout_ms,
+            session_inner.session.prepare(query),
+        )
+        .await
+        .map_err(|err| (CassError::from(&err), err.msg()))?;
 
         // Set Cpp Driver default configuration for queries:
         prepared.disable_paging();
@@ -404,6 +662,20 @@ pub unsafe extern "C" fn cass_session_close(session: *mut CassSession) -> *const
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_session_get_client_id(session: *const CassSession) -> CassUuid {
+    let cass_session = ptr_to_ref(session);
+
+    // Session which is not connected has no client id, so nil UUID is returned.
+    match cass_session.blocking_read().as_ref() {
+        Some(session_inner) => session_inner.client_id,
+        None => CassUuid {
+            time_and_version: 0,
+            clock_seq_and_node: 0,
+        },
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_session_get_schema_meta(
     session: *const CassSession,
@@ -411,13 +683,18 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
     let cass_session = ptr_to_ref(session);
     let mut keyspaces: HashMap<String, CassKeyspaceMeta> = HashMap::new();
 
-    for (keyspace_name, keyspace) in cass_session
-        .blocking_read()
-        .as_ref()
-        .unwrap()
-        .get_cluster_data()
-        .get_keyspace_info()
-    {
+    let session_guard = cass_session.blocking_read();
+    let session_inner = match session_guard.as_ref() {
+        Some(session_inner) => session_inner,
+        // Session which is not connected has no schema, so empty metadata is returned.
+        None => return Box::into_raw(Box::new(CassSchemaMeta { keyspaces })),
+    };
+
+    // Fields are queried when the session is connected and refreshed in the background,
+    // so getting the schema metadata does not wait for any request.
+    let schema_fields = session_inner.schema_fields.get();
+
+    for (keyspace_name, keyspace) in session_inner.session.get_cluster_data().get_keyspace_info() {
         let mut user_defined_type_data_type = HashMap::new();
         let mut tables = HashMap::new();
         let mut views = HashMap::new();
@@ -441,15 +718,28 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
                     table_metadata,
                     &keyspace.user_defined_types,
                 );
+                if let Some(fields) = schema_fields.table_fields(keyspace_name, table_name) {
+                    cass_table_meta.fields.extend(fields);
+                }
 
                 let mut table_views = HashMap::new();
-                for (view_name, view_metadata) in &keyspace.views {
-                    let cass_view_table_meta = create_table_metadata(
+                // Only views of this table, so each view refers to its own base table.
+                for (view_name, view_metadata) in keyspace
+                    .views
+                    .iter()
+                    .filter(|(_, view_metadata)| view_metadata.base_table_name == *table_name)
+                {
+                    let mut cass_view_table_meta = create_table_metadata(
                         keyspace_name,
                         view_name,
                         &view_metadata.view_metadata,
                         &keyspace.user_defined_types,
                     );
+                    cass_view_table_meta.fields = create_view_fields(
+                        keyspace_name,
+                        view_name,
+                        &view_metadata.base_table_name,
+                    );
                     let cass_view_meta = CassMaterializedViewMeta {
                         name: view_name.clone(),
                         view_metadata: cass_view_table_meta,
@@ -469,6 +759,11 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
             tables.insert(table_name.clone(), cass_table_meta_arced);
         }
 
+        let mut fields = create_keyspace_fields(keyspace_name, &keyspace.strategy);
+        if let Some(raw_fields) = schema_fields.keyspace_fields(keyspace_name) {
+            fields.extend(raw_fields);
+        }
+
         keyspaces.insert(
             keyspace_name.clone(),
             CassKeyspaceMeta {
@@ -476,6 +771,7 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
                 user_defined_type_data_type,
                 tables,
                 views,
+                fields,
             },
         );
     }
@@ -1,4 +1,4 @@
-use crate::argconv::{clone_arced, free_arced};
+use crate::argconv::{clone_arced, free_arced, ptr_to_cstr_n};
 use crate::cass_error::CassError;
 use crate::types::size_t;
 use libc::{c_int, strlen};
@@ -9,14 +9,19 @@ use openssl_sys::{
     SSL_CTX_set_verify, SSL_CTX_use_PrivateKey, SSL_CTX_use_certificate, TLS_method,
     X509_STORE_add_cert, X509_STORE_new, X509_free, BIO, SSL_CTX, X509_STORE,
 };
+use std::cell::Cell;
 use std::convert::TryInto;
+use std::ffi::OsStr;
 use std::os::raw::c_char;
 use std::os::raw::c_void;
+use std::path::PathBuf;
 use std::sync::Arc;
+use tracing::warn;
 
 pub struct CassSsl {
     pub(crate) ssl_context: *mut SSL_CTX,
     pub(crate) trusted_store: *mut X509_STORE,
+    pub(crate) verify_flags: Cell<i32>,
 }
 
 pub const CASS_SSL_VERIFY_NONE: i32 = 0x00;
@@ -41,11 +46,37 @@ pub unsafe extern "C" fn cass_ssl_new_no_lib_init() -> *const CassSsl {
     let ssl = CassSsl {
         ssl_context,
         trusted_store,
+        verify_flags: Cell::new(CASS_SSL_VERIFY_NONE),
     };
 
     Arc::into_raw(Arc::new(ssl)) as *const CassSsl
 }
 
+impl CassSsl {
+    fn verify_mode(&self) -> SslVerifyMode {
+        // Any of the peer verification flags requires the peer certificate to be verified.
+        if self.verify_flags.get() == CASS_SSL_VERIFY_NONE {
+            SslVerifyMode::NONE
+        } else {
+            SslVerifyMode::PEER
+        }
+    }
+
+    pub(crate) unsafe fn apply_verify_flags(&self) {
+        let flags = self.verify_flags.get();
+
+        if flags & (CASS_SSL_VERIFY_PEER_IDENTITY | CASS_SSL_VERIFY_PEER_IDENTITY_DNS) != 0 {
+            // FIXME: Rust Driver does not set the host name of the node on SSL connections,
+            // so the identity of the peer cannot be verified.
+            warn!(
+                "Verification of the peer identity is not supported, only the peer certificate is verified"
+            );
+        }
+
+        SSL_CTX_set_verify(self.ssl_context, self.verify_mode().bits(), None);
+    }
+}
+
 impl Drop for CassSsl {
     fn drop(&mut self) {
         unsafe {
@@ -130,28 +161,79 @@ pub unsafe extern "C" fn cass_ssl_add_trusted_cert_n(
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn cass_ssl_set_verify_flags(ssl: *mut CassSsl, flags: i32) {
-    let ssl = clone_arced(ssl);
+pub unsafe extern "C" fn cass_ssl_add_trusted_cert_dir(
+    ssl: *mut CassSsl,
+    dir: *const c_char,
+) -> CassError {
+    if dir.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
 
-    match flags {
-        CASS_SSL_VERIFY_NONE => {
-            SSL_CTX_set_verify(ssl.ssl_context, SslVerifyMode::NONE.bits(), None)
-        }
-        CASS_SSL_VERIFY_PEER_CERT => {
-            SSL_CTX_set_verify(ssl.ssl_context, SslVerifyMode::PEER.bits(), None)
+    cass_ssl_add_trusted_cert_dir_n(ssl, dir, strlen(dir).try_into().unwrap())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_ssl_add_trusted_cert_dir_n(
+    ssl: *mut CassSsl,
+    dir: *const c_char,
+    dir_length: size_t,
+) -> CassError {
+    let dir = match ptr_to_cstr_n(dir, dir_length) {
+        Some(dir) => dir,
+        None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    };
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            warn!(
+                "Unable to read trusted certificates directory {}: {}",
+                dir, err
+            );
+            return CassError::CASS_ERROR_LIB_BAD_PARAMS;
         }
-        _ => {
-            if flags & CASS_SSL_VERIFY_PEER_IDENTITY != 0 {
-                eprintln!("The CASS_SSL_VERIFY_PEER_CERT_IDENTITY is not supported, CASS_SSL_VERIFY_PEER_CERT is set in SSL context.");
-            }
+    };
 
-            if flags & CASS_SSL_VERIFY_PEER_IDENTITY_DNS != 0 {
-                eprintln!("The CASS_SSL_VERIFY_PEER_CERT_IDENTITY_DNS is not supported, CASS_SSL_VERIFY_PEER_CERT is set in SSL context.");
+    let mut cert_paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension() == Some(OsStr::new("pem")))
+        .collect();
+    // Files are added in the order of their names, so the result does not depend
+    // on the order in which the file system lists them.
+    cert_paths.sort();
+
+    for cert_path in cert_paths {
+        let cert = match std::fs::read(&cert_path) {
+            Ok(cert) => cert,
+            Err(err) => {
+                warn!(
+                    "Unable to read trusted certificate {:?}: {}",
+                    cert_path, err
+                );
+                return CassError::CASS_ERROR_SSL_INVALID_CERT;
             }
-
-            SSL_CTX_set_verify(ssl.ssl_context, SslVerifyMode::PEER.bits(), None);
+        };
+
+        let rc = cass_ssl_add_trusted_cert_n(
+            ssl,
+            cert.as_ptr() as *const c_char,
+            cert.len().try_into().unwrap(),
+        );
+        if rc != CassError::CASS_OK {
+            return rc;
         }
     }
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_ssl_set_verify_flags(ssl: *mut CassSsl, flags: i32) {
+    let ssl = clone_arced(ssl);
+
+    ssl.verify_flags.set(flags);
+    SSL_CTX_set_verify(ssl.ssl_context, ssl.verify_mode().bits(), None);
 }
 
 #[no_mangle]
@@ -1,9 +1,13 @@
 use crate::argconv::*;
 use crate::cass_error::CassError;
+use crate::custom_payload::CassCustomPayload;
 use crate::query_result::CassResult;
 use crate::retry_policy::CassRetryPolicy;
+use crate::session::CassSession;
 use crate::types::*;
-use scylla::frame::response::result::CqlValue;
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use scylla::frame::response::result::{ColumnType, CqlValue};
 use scylla::frame::types::LegacyConsistency::{Regular, Serial};
 use scylla::frame::types::{Consistency, LegacyConsistency};
 use scylla::frame::value::MaybeUnset;
@@ -13,8 +17,10 @@ use scylla::statement::prepared_statement::PreparedStatement;
 use scylla::statement::SerialConsistency;
 use scylla::Bytes;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::os::raw::{c_char, c_int};
 use std::sync::Arc;
+use uuid::Uuid;
 
 include!(concat!(env!("OUT_DIR"), "/cppdriver_data_query_error.rs"));
 
@@ -25,6 +31,24 @@ pub enum Statement {
     Prepared(Arc<PreparedStatement>),
 }
 
+impl Statement {
+    pub fn set_consistency(&mut self, consistency: Consistency) {
+        match self {
+            Statement::Simple(inner) => inner.query.set_consistency(consistency),
+            Statement::Prepared(inner) => Arc::make_mut(inner).set_consistency(consistency),
+        }
+    }
+
+    pub fn set_serial_consistency(&mut self, serial_consistency: Option<SerialConsistency>) {
+        match self {
+            Statement::Simple(inner) => inner.query.set_serial_consistency(serial_consistency),
+            Statement::Prepared(inner) => {
+                Arc::make_mut(inner).set_serial_consistency(serial_consistency)
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct SimpleQuery {
     pub query: Query,
@@ -36,16 +60,38 @@ pub struct CassStatement {
     pub bound_values: Vec<MaybeUnset<Option<CqlValue>>>,
     pub paging_state: Option<Bytes>,
     pub request_timeout_ms: Option<cass_uint64_t>,
+    // Consistencies set explicitly for the statement. They are applied when the
+    // statement is executed, taking precedence over the execution profile.
+    pub consistency: Option<Consistency>,
+    pub serial_consistency: Option<Option<SerialConsistency>>,
+    // FIXME: Rust Driver does not support sending custom payloads yet,
+    // so the payload is stored, but never sent.
+    pub custom_payload: Option<CassCustomPayload>,
+    pub exec_profile: Option<String>,
+    pub lazy_rows: bool,
 }
 
 impl CassStatement {
     fn bind_cql_value(&mut self, index: usize, value: Option<CqlValue>) -> CassError {
         if index as usize >= self.bound_values.len() {
-            CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS
-        } else {
-            self.bound_values[index] = Set(value);
-            CassError::CASS_OK
+            return CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS;
         }
+
+        let value = match &self.statement {
+            Statement::Prepared(prepared) => {
+                let column_type = &prepared.get_prepared_metadata().col_specs[index].typ;
+                match convert_to_column_type(value, column_type) {
+                    Ok(v) => v,
+                    Err(e) => return e,
+                }
+            }
+            Statement::Simple(_) => value,
+        };
+
+        // NULL is bound as `Set(None)`, so it is written as a tombstone - unlike `Unset`
+        // values (the initial state of each slot), which leave the column untouched.
+        self.bound_values[index] = Set(value);
+        CassError::CASS_OK
     }
 
     fn bind_multiple_values_by_name(
@@ -123,6 +169,138 @@ impl CassStatement {
     }
 }
 
+// Prepared statements know the types of their bound columns, so values
+// can be validated before they are sent to the server.
+fn convert_to_column_type(
+    value: Option<CqlValue>,
+    column_type: &ColumnType,
+) -> Result<Option<CqlValue>, CassError> {
+    if let Some(v) = &value {
+        validate_integer_range(v, column_type)?;
+    }
+
+    match (value, column_type) {
+        // Values bound with `cass_statement_bind_int32` or `cass_statement_bind_int64`
+        // are narrowed to the type of the column - they are already checked to be in its range.
+        (Some(CqlValue::Int(v)), ColumnType::TinyInt) => Ok(Some(CqlValue::TinyInt(v as i8))),
+        (Some(CqlValue::Int(v)), ColumnType::SmallInt) => Ok(Some(CqlValue::SmallInt(v as i16))),
+        (Some(CqlValue::BigInt(v)), ColumnType::TinyInt) => Ok(Some(CqlValue::TinyInt(v as i8))),
+        (Some(CqlValue::BigInt(v)), ColumnType::SmallInt) => Ok(Some(CqlValue::SmallInt(v as i16))),
+        (Some(CqlValue::BigInt(v)), ColumnType::Int) => Ok(Some(CqlValue::Int(v as i32))),
+        (Some(CqlValue::Uuid(uuid)), ColumnType::Timeuuid) => {
+            // Only time-based (version 1) UUIDs can be stored in timeuuid columns.
+            if uuid.get_version_num() != 1 {
+                return Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE);
+            }
+            Ok(Some(CqlValue::Timeuuid(uuid)))
+        }
+        // Timestamps (milliseconds since epoch) and times (nanoseconds since midnight)
+        // are bound with `cass_statement_bind_int64`.
+        (Some(CqlValue::BigInt(millis)), ColumnType::Timestamp) => Ok(Some(CqlValue::Timestamp(
+            chrono::Duration::milliseconds(millis),
+        ))),
+        (Some(CqlValue::BigInt(nanos)), ColumnType::Time) => {
+            Ok(Some(CqlValue::Time(chrono::Duration::nanoseconds(nanos))))
+        }
+        (Some(CqlValue::Blob(bytes)), ColumnType::Uuid | ColumnType::Timeuuid) => {
+            // Raw bytes of a UUID must be exactly 16 bytes long.
+            let uuid = match Uuid::from_slice(&bytes) {
+                Ok(uuid) => uuid,
+                Err(_) => return Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE),
+            };
+            convert_to_column_type(Some(CqlValue::Uuid(uuid)), column_type)
+        }
+        (Some(CqlValue::Blob(bytes)), ColumnType::Decimal) => {
+            // Raw bytes of a decimal are its serialized form - 4-byte scale
+            // followed by the unscaled value encoded as a varint (at least 1 byte).
+            if bytes.len() < 5 {
+                return Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE);
+            }
+            let (scale, varint) = bytes.split_at(4);
+            let scale = i32::from_be_bytes(<[u8; 4]>::try_from(scale).unwrap());
+            let unscaled = BigInt::from_signed_bytes_be(varint);
+            Ok(Some(CqlValue::Decimal(BigDecimal::new(
+                unscaled,
+                scale.into(),
+            ))))
+        }
+        (Some(CqlValue::Text(text)), ColumnType::Ascii) => {
+            // Text (varchar) columns accept any UTF-8 string, but ascii columns do not.
+            if !text.is_ascii() {
+                return Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE);
+            }
+            Ok(Some(CqlValue::Ascii(text)))
+        }
+        // Kind of a collection must match the kind of the column, and its elements
+        // are validated and converted the same way as values bound directly.
+        (Some(CqlValue::List(list)), ColumnType::List(element_type)) => Ok(Some(CqlValue::List(
+            convert_collection_elements(list, element_type)?,
+        ))),
+        (Some(CqlValue::Set(set)), ColumnType::Set(element_type)) => Ok(Some(CqlValue::Set(
+            convert_collection_elements(set, element_type)?,
+        ))),
+        (Some(CqlValue::Map(map)), ColumnType::Map(key_type, value_type)) => {
+            let map = map
+                .into_iter()
+                .map(|(key, value)| {
+                    Ok((
+                        convert_collection_element(key, key_type)?,
+                        convert_collection_element(value, value_type)?,
+                    ))
+                })
+                .collect::<Result<_, CassError>>()?;
+            Ok(Some(CqlValue::Map(map)))
+        }
+        (Some(CqlValue::List(_) | CqlValue::Set(_) | CqlValue::Map(_)), _) => {
+            Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE)
+        }
+        // Counter deltas are bound with `cass_statement_bind_int64` and are sent
+        // as bigints, which is the wire representation of counters, so no conversion is needed.
+        (value, _) => Ok(value),
+    }
+}
+
+fn convert_collection_element(
+    element: CqlValue,
+    element_type: &ColumnType,
+) -> Result<CqlValue, CassError> {
+    convert_to_column_type(Some(element), element_type)?
+        .ok_or(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE)
+}
+
+fn convert_collection_elements(
+    elements: Vec<CqlValue>,
+    element_type: &ColumnType,
+) -> Result<Vec<CqlValue>, CassError> {
+    elements
+        .into_iter()
+        .map(|element| convert_collection_element(element, element_type))
+        .collect()
+}
+
+fn validate_integer_range(value: &CqlValue, column_type: &ColumnType) -> Result<(), CassError> {
+    let v: i64 = match value {
+        CqlValue::TinyInt(v) => (*v).into(),
+        CqlValue::SmallInt(v) => (*v).into(),
+        CqlValue::Int(v) => (*v).into(),
+        CqlValue::BigInt(v) => *v,
+        _ => return Ok(()),
+    };
+
+    let is_in_range = match column_type {
+        ColumnType::TinyInt => i8::try_from(v).is_ok(),
+        ColumnType::SmallInt => i16::try_from(v).is_ok(),
+        ColumnType::Int => i32::try_from(v).is_ok(),
+        _ => true,
+    };
+
+    if is_in_range {
+        Ok(())
+    } else {
+        Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE)
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_statement_new(
     query: *const c_char,
@@ -155,9 +333,15 @@ pub unsafe extern "C" fn cass_statement_new_n(
 
     Box::into_raw(Box::new(CassStatement {
         statement: Statement::Simple(simple_query),
+        // Binding at an index not lower than `parameter_count` is out of bounds.
         bound_values: vec![Unset; parameter_count as usize],
         paging_state: None,
         request_timeout_ms: None,
+        consistency: None,
+        serial_consistency: None,
+        custom_payload: None,
+        exec_profile: None,
+        lazy_rows: false,
     }))
 }
 
@@ -174,10 +358,7 @@ pub unsafe extern "C" fn cass_statement_set_consistency(
     let consistency_opt = get_consistency_from_cass_consistency(consistency);
 
     if let Some(Regular(regular_consistency)) = consistency_opt {
-        match &mut ptr_to_ref_mut(statement).statement {
-            Statement::Simple(inner) => inner.query.set_consistency(regular_consistency),
-            Statement::Prepared(inner) => Arc::make_mut(inner).set_consistency(regular_consistency),
-        }
+        ptr_to_ref_mut(statement).consistency = Some(regular_consistency);
     }
 
     CassError::CASS_OK
@@ -189,6 +370,8 @@ pub unsafe extern "C" fn cass_statement_set_paging_size(
     page_size: c_int,
 ) -> CassError {
     // TODO: validate page_size
+    // The page size of the statement is always used, regardless of its execution
+    // profile, because Cpp Driver does not allow to set the page size for a profile.
     match &mut ptr_to_ref_mut(statement_raw).statement {
         Statement::Simple(inner) => {
             if page_size == -1 {
@@ -217,8 +400,13 @@ pub unsafe extern "C" fn cass_statement_set_paging_state(
     let statement = ptr_to_ref_mut(statement);
     let result = ptr_to_ref(result);
 
+    // Results of the last page have no paging state to continue from, so
+    // a previously set paging state is cleared rather than resumed from.
     statement.paging_state = result.metadata.paging_state.clone();
-    CassError::CASS_OK
+    match statement.paging_state {
+        Some(_) => CassError::CASS_OK,
+        None => CassError::CASS_ERROR_LIB_NO_PAGING_STATE,
+    }
 }
 
 #[no_mangle]
@@ -226,6 +414,9 @@ pub unsafe extern "C" fn cass_statement_set_is_idempotent(
     statement_raw: *mut CassStatement,
     is_idempotent: cass_bool_t,
 ) -> CassError {
+    // Idempotence is passed to the retry policy of the request, so e.g. the default
+    // retry policy retries batch log write timeouts and connection errors only
+    // of idempotent statements.
     match &mut ptr_to_ref_mut(statement_raw).statement {
         Statement::Simple(inner) => inner.query.set_is_idempotent(is_idempotent != 0),
         Statement::Prepared(inner) => Arc::make_mut(inner).set_is_idempotent(is_idempotent != 0),
@@ -234,6 +425,16 @@ pub unsafe extern "C" fn cass_statement_set_is_idempotent(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_lazy_rows(
+    statement_raw: *mut CassStatement,
+    enabled: cass_bool_t,
+) -> CassError {
+    ptr_to_ref_mut(statement_raw).lazy_rows = enabled != 0;
+
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_statement_set_tracing(
     statement_raw: *mut CassStatement,
@@ -280,12 +481,7 @@ pub unsafe extern "C" fn cass_statement_set_serial_consistency(
         _ => None,
     };
 
-    match &mut ptr_to_ref_mut(statement).statement {
-        Statement::Simple(inner) => inner.query.set_serial_consistency(serial_consistency),
-        Statement::Prepared(inner) => {
-            Arc::make_mut(inner).set_serial_consistency(serial_consistency)
-        }
-    }
+    ptr_to_ref_mut(statement).serial_consistency = Some(serial_consistency);
 
     CassError::CASS_OK
 }
@@ -329,6 +525,15 @@ pub unsafe extern "C" fn cass_statement_set_request_timeout(
     statement: *mut CassStatement,
     timeout_ms: cass_uint64_t,
 ) -> CassError {
+    let statement_from_raw = ptr_to_ref_mut(statement);
+
+    // CASS_UINT64_MAX disables the statement's timeout, so the one of the profile
+    // or the cluster is used.
+    if timeout_ms == cass_uint64_t::MAX {
+        statement_from_raw.request_timeout_ms = None;
+        return CassError::CASS_OK;
+    }
+
     // The maximum duration for a sleep is 68719476734 milliseconds (approximately 2.2 years).
     // Note: this is limited by tokio::time:timout
     // https://github.com/tokio-rs/tokio/blob/4b1c4801b1383800932141d0f6508d5b3003323e/tokio/src/time/driver/wheel/mod.rs#L44-L50
@@ -337,12 +542,92 @@ pub unsafe extern "C" fn cass_statement_set_request_timeout(
         return CassError::CASS_ERROR_LIB_BAD_PARAMS;
     }
 
-    let statement_from_raw = ptr_to_ref_mut(statement);
     statement_from_raw.request_timeout_ms = Some(timeout_ms);
 
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_custom_payload(
+    statement: *mut CassStatement,
+    payload: *const CassCustomPayload,
+) -> CassError {
+    let statement_from_raw = ptr_to_ref_mut(statement);
+    // FIXME: Rust Driver does not support sending custom payloads yet,
+    // so the payload is only stored in the statement.
+    statement_from_raw.custom_payload = payload.as_ref().cloned();
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_execution_profile(
+    statement: *mut CassStatement,
+    name: *const c_char,
+) -> CassError {
+    cass_statement_set_execution_profile_n(statement, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_execution_profile_n(
+    statement: *mut CassStatement,
+    name: *const c_char,
+    name_length: size_t,
+) -> CassError {
+    let statement_from_raw = ptr_to_ref_mut(statement);
+
+    // NULL or empty name clears the execution profile of the statement.
+    if name.is_null() || name_length == 0 {
+        statement_from_raw.exec_profile = None;
+        return CassError::CASS_OK;
+    }
+
+    match ptr_to_cstr_n(name, name_length) {
+        Some(name) => {
+            statement_from_raw.exec_profile = Some(name.to_string());
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_execution_profile_checked(
+    statement: *mut CassStatement,
+    session: *const CassSession,
+    name: *const c_char,
+) -> CassError {
+    cass_statement_set_execution_profile_checked_n(statement, session, name, strlen(name))
+}
+
+// Unlike cass_statement_set_execution_profile_n, the profile is validated eagerly,
+// so a name which is not registered in the session is rejected right away.
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_execution_profile_checked_n(
+    statement: *mut CassStatement,
+    session: *const CassSession,
+    name: *const c_char,
+    name_length: size_t,
+) -> CassError {
+    // NULL or empty name clears the execution profile, so there is nothing to validate.
+    if !name.is_null() && name_length != 0 {
+        let name_str = match ptr_to_cstr_n(name, name_length) {
+            Some(name_str) => name_str,
+            None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+        };
+
+        // Blocking read panics within the Tokio runtime, so this function must not
+        // be called from a future callback (as documented in the header).
+        match ptr_to_ref(session).blocking_read().as_ref() {
+            Some(session_inner) if session_inner.has_exec_profile(name_str) => {}
+            Some(_) => return CassError::CASS_ERROR_LIB_EXECUTION_PROFILE_INVALID,
+            None => return CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+        }
+    }
+
+    cass_statement_set_execution_profile_n(statement, name, name_length)
+}
+
 prepare_binders_macro!(@index_and_name CassStatement,
     |s: &mut CassStatement, idx, v| s.bind_cql_value(idx, v),
     |s: &mut CassStatement, name, v| s.bind_cql_value_by_name(name, v));
@@ -1 +1,116 @@
+use crate::argconv::*;
+use crate::cluster::CassCluster;
+use crate::types::*;
+use scylla::frame::Compression;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_uint};
 
+// Functions below are used only by the integration tests to inspect
+// the configuration, which is not accessible through the public API.
+
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_token_aware_shuffle_replicas(
+    cluster_raw: *const CassCluster,
+) -> cass_bool_t {
+    let cluster = ptr_to_ref(cluster_raw);
+    cluster.token_aware_shuffling_replicas_enabled() as cass_bool_t
+}
+
+// Returns 0 when heartbeats are disabled.
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_connection_heartbeat_interval(
+    cluster_raw: *const CassCluster,
+) -> c_uint {
+    let cluster = ptr_to_ref(cluster_raw);
+    cluster
+        .connection_heartbeat_interval()
+        .map_or(0, |interval| interval.as_secs() as c_uint)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_connection_idle_timeout(
+    cluster_raw: *const CassCluster,
+) -> c_uint {
+    let cluster = ptr_to_ref(cluster_raw);
+    cluster.connection_idle_timeout().as_secs() as c_uint
+}
+
+// Returns -1 when the delay is not set.
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_coalesce_delay(
+    cluster_raw: *const CassCluster,
+) -> cass_int64_t {
+    let cluster = ptr_to_ref(cluster_raw);
+    cluster
+        .coalesce_delay()
+        .map_or(-1, |delay| delay.as_micros() as cass_int64_t)
+}
+
+// Returns false when the cluster does not use the DC-aware policy.
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_dc_aware_permit_dc_failover(
+    cluster_raw: *const CassCluster,
+) -> cass_bool_t {
+    let cluster = ptr_to_ref(cluster_raw);
+    cluster.dc_aware_permit_dc_failover().unwrap_or(false) as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_compression(
+    cluster_raw: *const CassCluster,
+) -> CassCompressionType {
+    let cluster = ptr_to_ref(cluster_raw);
+    match cluster.compression() {
+        Some(Compression::Lz4) => CassCompressionType::CASS_COMPRESSION_LZ4,
+        Some(Compression::Snappy) => CassCompressionType::CASS_COMPRESSION_SNAPPY,
+        None => CassCompressionType::CASS_COMPRESSION_NONE,
+    }
+}
+
+// Writes 0 to both bounds when the range is not set.
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_local_port_range(
+    cluster_raw: *const CassCluster,
+    lo: *mut c_int,
+    hi: *mut c_int,
+) {
+    let cluster = ptr_to_ref(cluster_raw);
+    let (range_lo, range_hi) = cluster.local_port_range().unwrap_or((0, 0));
+    *lo = range_lo as c_int;
+    *hi = range_hi as c_int;
+}
+
+#[no_mangle]
+pub extern "C" fn testing_debug_assertions_enabled() -> cass_bool_t {
+    cfg!(debug_assertions) as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_set_contact_point_shuffle_seed(
+    cluster_raw: *mut CassCluster,
+    seed: cass_uint64_t,
+) {
+    let cluster = ptr_to_ref_mut(cluster_raw);
+    cluster.set_contact_point_shuffle_seed(seed);
+}
+
+// Returns the contact points (with ports) joined with commas, in the order in which
+// sessions built from the cluster try them. The string must be freed
+// with `testing_free_cstring`.
+#[no_mangle]
+pub unsafe extern "C" fn testing_cluster_get_known_nodes(
+    cluster_raw: *const CassCluster,
+) -> *mut c_char {
+    let cluster = ptr_to_ref(cluster_raw);
+    // Contact points are set from C strings, so they do not contain NUL bytes.
+    CString::new(cluster.known_nodes().join(","))
+        .unwrap_or_default()
+        .into_raw()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn testing_free_cstring(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
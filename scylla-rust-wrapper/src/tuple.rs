@@ -109,5 +109,30 @@ make_binders!(bytes, cass_tuple_set_bytes);
 make_binders!(uuid, cass_tuple_set_uuid);
 make_binders!(inet, cass_tuple_set_inet);
 make_binders!(collection, cass_tuple_set_collection);
-make_binders!(tuple, cass_tuple_set_tuple);
 make_binders!(user_type, cass_tuple_set_user_type);
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_tuple_set_tuple(
+    tuple: *mut CassTuple,
+    index: size_t,
+    value: *const CassTuple,
+) -> CassError {
+    let tuple = ptr_to_ref_mut(tuple);
+    let value = ptr_to_ref(value);
+    let index = index as usize;
+
+    // Only tuples and UDTs among the values of a nested tuple are checked by `bind_value`,
+    // so if both tuples have types, the declared type of the nested one is checked as well.
+    if let (Some(inner_types), Some(value_type)) = (tuple.get_types(), &value.data_type) {
+        if let Some(inner_type) = inner_types.get(index) {
+            if !binding::is_compatible_data_type(inner_type, value_type) {
+                return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE;
+            }
+        }
+    }
+
+    match CqlValue::try_from(value) {
+        Ok(v) => tuple.bind_value(index, Some(v)),
+        Err(e) => e,
+    }
+}
@@ -139,16 +139,21 @@ pub unsafe extern "C" fn cass_uuid_gen_time(uuid_gen: *mut CassUuidGen, output:
     output.clock_seq_and_node = uuid_gen.clock_seq_and_node;
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn cass_uuid_gen_random(_uuid_gen: *mut CassUuidGen, output: *mut CassUuid) {
-    let output = ptr_to_ref_mut(output);
-
+pub fn gen_random_uuid() -> CassUuid {
     let time_and_version: u64 = rand::random();
     let clock_seq_and_node: u64 = rand::random();
 
-    output.time_and_version = set_version(time_and_version, 4);
-    output.clock_seq_and_node = (clock_seq_and_node & 0x3FFFFFFFFFFFFFFF) | 0x8000000000000000;
-    // RFC4122 variant
+    CassUuid {
+        time_and_version: set_version(time_and_version, 4),
+        // RFC4122 variant
+        clock_seq_and_node: (clock_seq_and_node & 0x3FFFFFFFFFFFFFFF) | 0x8000000000000000,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_uuid_gen_random(_uuid_gen: *mut CassUuidGen, output: *mut CassUuid) {
+    let output = ptr_to_ref_mut(output);
+    *output = gen_random_uuid();
 }
 
 #[no_mangle]
@@ -21,6 +21,7 @@
 #include "get_time.hpp"
 #include "logger.hpp"
 #include "murmur3.hpp"
+#include "testing_rust_impls.h"
 
 namespace datastax { namespace internal { namespace testing {
 
@@ -46,6 +47,49 @@ String get_contact_points_from_cluster(CassCluster* cluster) {
   throw std::runtime_error("Unimplemented 'get_contact_points_from_cluster'!");
 }
 
+bool get_token_aware_shuffle_replicas_from_cluster(CassCluster* cluster) {
+  return testing_cluster_get_token_aware_shuffle_replicas(cluster) == cass_true;
+}
+
+unsigned get_connection_heartbeat_interval_from_cluster(CassCluster* cluster) {
+  return testing_cluster_get_connection_heartbeat_interval(cluster);
+}
+
+unsigned get_connection_idle_timeout_from_cluster(CassCluster* cluster) {
+  return testing_cluster_get_connection_idle_timeout(cluster);
+}
+
+void set_contact_point_shuffle_seed_on_cluster(CassCluster* cluster, uint64_t seed) {
+  testing_cluster_set_contact_point_shuffle_seed(cluster, seed);
+}
+
+String get_known_nodes_from_cluster(CassCluster* cluster) {
+  char* known_nodes = testing_cluster_get_known_nodes(cluster);
+  String result(known_nodes);
+  testing_free_cstring(known_nodes);
+  return result;
+}
+
+int64_t get_coalesce_delay_from_cluster(CassCluster* cluster) {
+  return testing_cluster_get_coalesce_delay(cluster);
+}
+
+bool get_dc_aware_permit_dc_failover_from_cluster(CassCluster* cluster) {
+  return testing_cluster_get_dc_aware_permit_dc_failover(cluster) == cass_true;
+}
+
+CassCompressionType get_compression_from_cluster(CassCluster* cluster) {
+  return testing_cluster_get_compression(cluster);
+}
+
+std::pair<int, int> get_local_port_range_from_cluster(CassCluster* cluster) {
+  std::pair<int, int> range;
+  testing_cluster_get_local_port_range(cluster, &range.first, &range.second);
+  return range;
+}
+
+bool get_debug_assertions_enabled() { return testing_debug_assertions_enabled() == cass_true; }
+
 int64_t create_murmur3_hash_from_string(const String& value) {
   return MurmurHash3_x64_128(value.data(), value.size(), 0);
 }
@@ -23,6 +23,7 @@
 #include "vector.hpp"
 
 #include <stdint.h>
+#include <utility>
 
 namespace datastax { namespace internal { namespace testing {
 
@@ -36,6 +37,26 @@ CASS_EXPORT int get_port_from_cluster(CassCluster* cluster);
 
 CASS_EXPORT String get_contact_points_from_cluster(CassCluster* cluster);
 
+CASS_EXPORT bool get_token_aware_shuffle_replicas_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT unsigned get_connection_heartbeat_interval_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT unsigned get_connection_idle_timeout_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT void set_contact_point_shuffle_seed_on_cluster(CassCluster* cluster, uint64_t seed);
+
+CASS_EXPORT String get_known_nodes_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT int64_t get_coalesce_delay_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT bool get_dc_aware_permit_dc_failover_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT CassCompressionType get_compression_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT std::pair<int, int> get_local_port_range_from_cluster(CassCluster* cluster);
+
+CASS_EXPORT bool get_debug_assertions_enabled();
+
 CASS_EXPORT uint64_t get_host_latency_average(CassSession* session, String ip_address, int port);
 
 CASS_EXPORT CassConsistency get_consistency(const CassStatement* statement);
@@ -0,0 +1,42 @@
+#ifndef CPP_RUST_DRIVER_TESTING_RUST_IMPLS_HPP
+#define CPP_RUST_DRIVER_TESTING_RUST_IMPLS_HPP
+
+#include "cassandra.h"
+
+extern "C" {
+// Returns whether sessions built from the cluster shuffle replicas in token-aware routing.
+CASS_EXPORT cass_bool_t testing_cluster_get_token_aware_shuffle_replicas(CassCluster* cluster);
+
+// Returns the heartbeat interval in seconds (0 when heartbeats are disabled).
+CASS_EXPORT unsigned testing_cluster_get_connection_heartbeat_interval(CassCluster* cluster);
+
+// Returns the connection idle timeout in seconds.
+CASS_EXPORT unsigned testing_cluster_get_connection_idle_timeout(CassCluster* cluster);
+
+// Sets the seed of shuffling the contact points, so their order is reproducible.
+CASS_EXPORT void testing_cluster_set_contact_point_shuffle_seed(CassCluster* cluster,
+                                                                cass_uint64_t seed);
+
+// Returns the contact points joined with commas, in the order in which they are tried.
+// The returned string must be freed with `testing_free_cstring`.
+CASS_EXPORT char* testing_cluster_get_known_nodes(CassCluster* cluster);
+
+CASS_EXPORT void testing_free_cstring(char* s);
+
+// Returns the coalesce delay in microseconds (-1 when it is not set).
+CASS_EXPORT cass_int64_t testing_cluster_get_coalesce_delay(CassCluster* cluster);
+
+// Returns whether the DC-aware policy may fail over to remote DCs (false if not DC-aware).
+CASS_EXPORT cass_bool_t testing_cluster_get_dc_aware_permit_dc_failover(CassCluster* cluster);
+
+// Returns the compression used by sessions built from the cluster.
+CASS_EXPORT CassCompressionType testing_cluster_get_compression(CassCluster* cluster);
+
+// Gets the local port range (both bounds are 0 when it is not set).
+CASS_EXPORT void testing_cluster_get_local_port_range(CassCluster* cluster, int* lo, int* hi);
+
+// Returns whether the driver was built with debug assertions (e.g. double free detection).
+CASS_EXPORT cass_bool_t testing_debug_assertions_enabled();
+}
+
+#endif
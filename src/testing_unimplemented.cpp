@@ -53,11 +53,6 @@ cass_authenticator_set_error(CassAuthenticator* auth,
 	throw std::runtime_error("UNIMPLEMENTED cass_authenticator_set_error\n");
 }
 CASS_EXPORT CassError
-cass_batch_set_execution_profile(CassBatch* batch,
-                                 const char* name){
-	throw std::runtime_error("UNIMPLEMENTED cass_batch_set_execution_profile\n");
-}
-CASS_EXPORT CassError
 cass_batch_set_keyspace(CassBatch* batch,
                         const char* keyspace){
 	throw std::runtime_error("UNIMPLEMENTED cass_batch_set_keyspace\n");
@@ -85,16 +80,6 @@ cass_cluster_set_cloud_secure_connection_bundle_no_ssl_lib_init(CassCluster* clu
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_cloud_secure_connection_bundle_no_ssl_lib_init\n");
 }
 CASS_EXPORT void
-cass_cluster_set_connection_heartbeat_interval(CassCluster* cluster,
-                                               unsigned interval_secs){
-	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_connection_heartbeat_interval\n");
-}
-CASS_EXPORT void
-cass_cluster_set_connection_idle_timeout(CassCluster* cluster,
-                                         unsigned timeout_secs){
-	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_connection_idle_timeout\n");
-}
-CASS_EXPORT void
 cass_cluster_set_constant_reconnect(CassCluster* cluster,
                                     cass_uint64_t delay_ms){
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_constant_reconnect\n");
@@ -104,18 +89,6 @@ cass_cluster_set_core_connections_per_host(CassCluster* cluster,
                                            unsigned num_connections){
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_core_connections_per_host\n");
 }
-CASS_EXPORT CassError
-cass_cluster_set_execution_profile(CassCluster* cluster,
-                                   const char* name,
-                                   CassExecProfile* profile){
-	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_execution_profile\n");
-}
-CASS_EXPORT CassError
-cass_cluster_set_host_listener_callback(CassCluster* cluster,
-                                        CassHostListenerCallback callback,
-                                        void* data){
-	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_host_listener_callback\n");
-}
 CASS_EXPORT void
 cass_cluster_set_latency_aware_routing(CassCluster* cluster,
                                        cass_bool_t enabled){
@@ -136,11 +109,6 @@ cass_cluster_set_local_address(CassCluster* cluster,
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_local_address\n");
 }
 CASS_EXPORT CassError
-cass_cluster_set_no_compact(CassCluster* cluster,
-                            cass_bool_t enabled){
-	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_no_compact\n");
-}
-CASS_EXPORT CassError
 cass_cluster_set_num_threads_io(CassCluster* cluster,
                                 unsigned num_threads){
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_num_threads_io\n");
@@ -156,11 +124,6 @@ cass_cluster_set_prepare_on_up_or_add_host(CassCluster* cluster,
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_prepare_on_up_or_add_host\n");
 }
 CASS_EXPORT void
-cass_cluster_set_request_timeout(CassCluster* cluster,
-                                 unsigned timeout_ms){
-	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_request_timeout\n");
-}
-CASS_EXPORT void
 cass_cluster_set_retry_policy(CassCluster* cluster,
                               CassRetryPolicy* retry_policy){
 	throw std::runtime_error("UNIMPLEMENTED cass_cluster_set_retry_policy\n");
@@ -206,25 +169,6 @@ cass_column_meta_field_by_name(const CassColumnMeta* column_meta,
                                const char* name){
 	throw std::runtime_error("UNIMPLEMENTED cass_column_meta_field_by_name\n");
 }
-CASS_EXPORT void
-cass_custom_payload_free(CassCustomPayload* payload){
-	throw std::runtime_error("UNIMPLEMENTED cass_custom_payload_free\n");
-}
-CASS_EXPORT void
-cass_custom_payload_set(CassCustomPayload* payload,
-                        const char* name,
-                        const cass_byte_t* value,
-                        size_t value_size){
-	throw std::runtime_error("UNIMPLEMENTED cass_custom_payload_set\n");
-}
-CASS_EXPORT void
-cass_execution_profile_free(CassExecProfile* profile){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_free\n");
-}
-CASS_EXPORT CassExecProfile*
-cass_execution_profile_new(){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_new\n");
-}
 CASS_EXPORT CassError
 cass_execution_profile_set_blacklist_dc_filtering(CassExecProfile* profile,
                                                   const char* dcs){
@@ -236,53 +180,11 @@ cass_execution_profile_set_blacklist_filtering(CassExecProfile* profile,
 	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_blacklist_filtering\n");
 }
 CASS_EXPORT CassError
-cass_execution_profile_set_consistency(CassExecProfile* profile,
-                                       CassConsistency consistency){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_consistency\n");
-}
-CASS_EXPORT CassError
-cass_execution_profile_set_constant_speculative_execution_policy(CassExecProfile* profile,
-                                                                 cass_int64_t constant_delay_ms,
-                                                                 int max_speculative_executions){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_constant_speculative_execution_policy\n");
-}
-CASS_EXPORT CassError
-cass_execution_profile_set_latency_aware_routing(CassExecProfile* profile,
-                                                 cass_bool_t enabled){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_latency_aware_routing\n");
-}
-CASS_EXPORT CassError
-cass_execution_profile_set_load_balance_dc_aware(CassExecProfile* profile,
-                                                 const char* local_dc,
-                                                 unsigned used_hosts_per_remote_dc,
-                                                 cass_bool_t allow_remote_dcs_for_local_cl){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_load_balance_dc_aware\n");
-}
-CASS_EXPORT CassError
-cass_execution_profile_set_load_balance_round_robin(CassExecProfile* profile){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_load_balance_round_robin\n");
-}
-CASS_EXPORT CassError
-cass_execution_profile_set_request_timeout(CassExecProfile* profile,
-                                           cass_uint64_t timeout_ms){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_request_timeout\n");
-}
-CASS_EXPORT CassError
 cass_execution_profile_set_retry_policy(CassExecProfile* profile,
                                         CassRetryPolicy* retry_policy){
 	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_retry_policy\n");
 }
 CASS_EXPORT CassError
-cass_execution_profile_set_serial_consistency(CassExecProfile* profile,
-                                              CassConsistency serial_consistency){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_serial_consistency\n");
-}
-CASS_EXPORT CassError
-cass_execution_profile_set_token_aware_routing(CassExecProfile* profile,
-                                               cass_bool_t enabled){
-	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_token_aware_routing\n");
-}
-CASS_EXPORT CassError
 cass_execution_profile_set_whitelist_dc_filtering(CassExecProfile* profile,
                                                   const char* dcs){
 	throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_whitelist_dc_filtering\n");
@@ -381,11 +283,6 @@ CASS_EXPORT cass_bool_t
 cass_keyspace_meta_is_virtual(const CassKeyspaceMeta* keyspace_meta){
 	throw std::runtime_error("UNIMPLEMENTED cass_keyspace_meta_is_virtual\n");
 }
-CASS_EXPORT const CassValue*
-cass_materialized_view_meta_field_by_name(const CassMaterializedViewMeta* view_meta,
-                                          const char* name){
-	throw std::runtime_error("UNIMPLEMENTED cass_materialized_view_meta_field_by_name\n");
-}
 CASS_EXPORT const CassDataType*
 cass_prepared_parameter_data_type_by_name(const CassPrepared* prepared,
                                           const char* name){
@@ -406,11 +303,6 @@ cass_session_connect_keyspace(CassSession* session,
 	throw std::runtime_error("UNIMPLEMENTED cass_session_connect_keyspace\n");
 }
 CASS_EXPORT void
-cass_session_get_metrics(const CassSession* session,
-                         CassMetrics* output){
-	throw std::runtime_error("UNIMPLEMENTED cass_session_get_metrics\n");
-}
-CASS_EXPORT void
 cass_session_get_speculative_execution_metrics(const CassSession* session,
                                                CassSpeculativeExecutionMetrics* output){
 	throw std::runtime_error("UNIMPLEMENTED cass_session_get_speculative_execution_metrics\n");
@@ -469,16 +361,6 @@ cass_statement_bind_duration_by_name(CassStatement* statement,
 	throw std::runtime_error("UNIMPLEMENTED cass_statement_bind_duration_by_name\n");
 }
 CASS_EXPORT CassError
-cass_statement_set_custom_payload(CassStatement* statement,
-                                  const CassCustomPayload* payload){
-	throw std::runtime_error("UNIMPLEMENTED cass_statement_set_custom_payload\n");
-}
-CASS_EXPORT CassError
-cass_statement_set_execution_profile(CassStatement* statement,
-                                     const char* name){
-	throw std::runtime_error("UNIMPLEMENTED cass_statement_set_execution_profile\n");
-}
-CASS_EXPORT CassError
 cass_statement_set_host(CassStatement* statement,
                         const char* host,
                         int port){
@@ -505,11 +387,6 @@ cass_table_meta_clustering_key_order(const CassTableMeta* table_meta,
                                      size_t index){
 	throw std::runtime_error("UNIMPLEMENTED cass_table_meta_clustering_key_order\n");
 }
-CASS_EXPORT const CassValue*
-cass_table_meta_field_by_name(const CassTableMeta* table_meta,
-                              const char* name){
-	throw std::runtime_error("UNIMPLEMENTED cass_table_meta_field_by_name\n");
-}
 CASS_EXPORT const CassIndexMeta*
 cass_table_meta_index_by_name(const CassTableMeta* table_meta,
                                const char* index){
@@ -579,17 +456,3 @@ cass_user_type_set_duration_by_name(CassUserType* user_type,
                                     cass_int64_t nanos){
 	throw std::runtime_error("UNIMPLEMENTED cass_user_type_set_duration_by_name\n");
 }
-CASS_EXPORT CassError
-cass_value_get_decimal(const CassValue* value,
-                       const cass_byte_t** varint,
-                       size_t* varint_size,
-                       cass_int32_t* scale){
-	throw std::runtime_error("UNIMPLEMENTED cass_value_get_decimal\n");
-}
-CASS_EXPORT CassError
-cass_value_get_duration(const CassValue* value,
-                        cass_int32_t* months,
-                        cass_int32_t* days,
-                        cass_int64_t* nanos){
-	throw std::runtime_error("UNIMPLEMENTED cass_value_get_duration\n");
-}
\ No newline at end of file
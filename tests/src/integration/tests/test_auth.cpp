@@ -270,3 +270,21 @@ CASSANDRA_INTEGRATION_TEST_F(AuthenticationTests, AuthenticatorSetErrorNull) {
   ASSERT_EQ(session.connect_error_code(), CASS_ERROR_SERVER_BAD_CREDENTIALS);
   ASSERT_GE(logger_.count(), 1u);
 }
+
+/**
+ * Perform a session connection with invalid credentials
+ *
+ * This test will ensure that the error returned by the server while
+ * connecting is reported with its specific code.
+ *
+ * @test_category authentication
+ * @test_category error_codes
+ * @expected_result Session will not be established (bad credentials)
+ */
+CASSANDRA_INTEGRATION_TEST_F(AuthenticationTests, BadCredentialsErrorCode) {
+  CHECK_FAILURE;
+
+  Session session =
+      connect_using_credentials(CASS_HIGHEST_SUPPORTED_PROTOCOL_VERSION, "invalid", "invalid");
+  EXPECT_EQ(CASS_ERROR_SERVER_BAD_CREDENTIALS, session.connect_error_code());
+}
@@ -16,6 +16,8 @@
 
 #include "integration.hpp"
 
+#include <limits>
+
 /**
  * Basics integration tests; common operations
  */
@@ -571,3 +573,1474 @@ CASSANDRA_INTEGRATION_TEST_F(BasicsTests, PagingEmpty) {
   std::string token = result.paging_state_token();
   EXPECT_TRUE(token.empty());
 }
+
+/**
+ * Perform an insert and ensure its result without rows can be accessed safely
+ *
+ * This test will perform an insert and ensure that the first row of its result
+ * is NULL and the row count is 0.
+ *
+ * @test_category queries:basic
+ * @expected_result First row is NULL and row count is 0
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, NonRowsResult) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  Result result = session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "0"));
+
+  EXPECT_TRUE(cass_result_first_row(result.get()) == NULL);
+  EXPECT_EQ(0u, cass_result_row_count(result.get()));
+}
+
+/**
+ * Verify that clearing the paging state token restarts paging from the first page.
+ *
+ * @expected_result Clearing the token returns CASS_OK and the next execution returns the first
+ *                  page again.
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, PagingStateTokenClear) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_COMPOSITE_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+
+  { // Insert rows
+    Statement insert_statement(format_string(CASSANDRA_COMPOSITE_KEY_VALUE_INSERT_FORMAT,
+                                             table_name_.c_str(), "0", "?", "?"),
+                               2);
+
+    for (int i = 0; i < 20; ++i) {
+      insert_statement.bind<TimeUuid>(0, uuid_generator_.generate_timeuuid());
+      insert_statement.bind<Integer>(1, Integer(i));
+      session_.execute(insert_statement);
+    }
+  }
+
+  Statement select_statement(
+      format_string(CASSANDRA_COMPOSITE_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  select_statement.set_paging_size(10);
+
+  Result result = session_.execute(select_statement);
+  EXPECT_EQ(Integer(0), result.first_row().next().as<Integer>());
+
+  // Continue from the second page
+  select_statement.set_paging_state_token(result.paging_state_token());
+  result = session_.execute(select_statement);
+  EXPECT_EQ(Integer(10), result.first_row().next().as<Integer>());
+
+  // Clear the token and start from the first page again
+  EXPECT_EQ(CASS_OK, cass_statement_set_paging_state_token(select_statement.get(), NULL, 0));
+  result = session_.execute(select_statement);
+  EXPECT_EQ(Integer(0), result.first_row().next().as<Integer>());
+}
+
+/**
+ * Perform an insert into a varchar column and read it back as a string
+ *
+ * This test will ensure that `varchar`, being an alias of `text`, is read
+ * using cass_value_get_string().
+ *
+ * @test_category data_types:primitive
+ * @expected_result The inserted string is read back
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, Varchar) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "varchar"));
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0",
+                                 "'varchar value'"));
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  const CassValue* value = result.first_row().next().get_value();
+
+  const char* output;
+  size_t output_size;
+  ASSERT_EQ(CASS_OK, cass_value_get_string(value, &output, &output_size));
+  EXPECT_EQ(std::string("varchar value"), std::string(output, output_size));
+}
+
+/**
+ * Verify that the item count of a map matches the number of steps of its iterator
+ *
+ * This test will ensure that cass_value_item_count() returns the number of
+ * key-value entries of a map (not the number of keys and values) and that
+ * the map iterator visits every entry once.
+ *
+ * @test_category data_types:collections
+ * @expected_result Both the item count and the number of iterations equal the number of entries
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, MapItemCount) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "map<int, int>"));
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0",
+                                 "{ 1: 2, 3: 4, 5: 6 }"));
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  const CassValue* value = result.first_row().next().get_value();
+  EXPECT_EQ(3u, cass_value_item_count(value));
+
+  CassIterator* iterator = cass_iterator_from_map(value);
+  ASSERT_TRUE(iterator != NULL);
+  size_t count = 0;
+  while (cass_iterator_next(iterator)) {
+    count++;
+  }
+  cass_iterator_free(iterator);
+  EXPECT_EQ(cass_value_item_count(value), count);
+}
+
+/**
+ * Verify that paging using the base64 encoded paging state token properly returns rows.
+ *
+ * @expected_result The base64 token round-trips and paging continues from the next page.
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, PagingStateTokenBase64) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_COMPOSITE_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+
+  { // Insert rows
+    Statement insert_statement(format_string(CASSANDRA_COMPOSITE_KEY_VALUE_INSERT_FORMAT,
+                                             table_name_.c_str(), "0", "?", "?"),
+                               2);
+
+    for (int i = 0; i < 20; ++i) {
+      insert_statement.bind<TimeUuid>(0, uuid_generator_.generate_timeuuid());
+      insert_statement.bind<Integer>(1, Integer(i));
+      session_.execute(insert_statement);
+    }
+  }
+
+  Statement select_statement(
+      format_string(CASSANDRA_COMPOSITE_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  select_statement.set_paging_size(10);
+  Result result = session_.execute(select_statement);
+  ASSERT_TRUE(result.has_more_pages());
+
+  const char* token;
+  size_t token_size;
+  ASSERT_EQ(CASS_OK, cass_result_paging_state_token_base64(result.get(), &token, &token_size));
+  std::string encoded(token, token_size);
+  EXPECT_EQ(0u, encoded.size() % 4);
+  EXPECT_EQ(std::string::npos,
+            encoded.find_first_not_of(
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/="));
+
+  // The token is encoded once, so the same pointer is returned again
+  const char* token_again;
+  ASSERT_EQ(CASS_OK,
+            cass_result_paging_state_token_base64(result.get(), &token_again, &token_size));
+  EXPECT_EQ(token, token_again);
+
+  // Continue from the second page using the decoded token
+  ASSERT_EQ(CASS_OK, cass_statement_set_paging_state_token_base64(
+                         select_statement.get(), encoded.c_str(), encoded.size()));
+  result = session_.execute(select_statement);
+  EXPECT_EQ(10u, result.row_count());
+  EXPECT_EQ(Integer(10), result.first_row().next().as<Integer>());
+
+  // Malformed tokens are rejected
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "abc", 3));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "a*c=", 4));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "a===", 4));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "ab=c", 4));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "AQ==AQ==", 8));
+
+  // Tokens with one or two padding characters are accepted
+  EXPECT_EQ(CASS_OK,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "AQ==", 4));
+  EXPECT_EQ(CASS_OK,
+            cass_statement_set_paging_state_token_base64(select_statement.get(), "AQI=", 4));
+}
+
+/**
+ * Verify that setting the paging state from the last page clears the paging state
+ *
+ * This test will page through a table and ensure that after
+ * `cass_statement_set_paging_state()` fails for the last page, executing the
+ * statement again starts from the first page instead of a stale one.
+ *
+ * @test_category queries:paging
+ * @expected_result CASS_ERROR_LIB_NO_PAGING_STATE is returned and the first page is read
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, SetPagingStateFromLastPage) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_COMPOSITE_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+
+  { // Insert rows
+    Statement insert_statement(format_string(CASSANDRA_COMPOSITE_KEY_VALUE_INSERT_FORMAT,
+                                             table_name_.c_str(), "0", "?", "?"),
+                               2);
+
+    for (int i = 0; i < 15; ++i) {
+      insert_statement.bind<TimeUuid>(0, uuid_generator_.generate_timeuuid());
+      insert_statement.bind<Integer>(1, Integer(i));
+      session_.execute(insert_statement);
+    }
+  }
+
+  Statement select_statement(
+      format_string(CASSANDRA_COMPOSITE_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  select_statement.set_paging_size(10);
+  Result result = session_.execute(select_statement);
+  ASSERT_TRUE(result.has_more_pages());
+
+  ASSERT_EQ(CASS_OK, cass_statement_set_paging_state(select_statement.get(), result.get()));
+  result = session_.execute(select_statement);
+  EXPECT_EQ(5u, result.row_count());
+  ASSERT_FALSE(result.has_more_pages());
+
+  EXPECT_EQ(CASS_ERROR_LIB_NO_PAGING_STATE,
+            cass_statement_set_paging_state(select_statement.get(), result.get()));
+  result = session_.execute(select_statement);
+  EXPECT_EQ(10u, result.row_count());
+  EXPECT_EQ(Integer(0), result.first_row().next().as<Integer>());
+}
+
+/**
+ * Verify comparing values using cass_value_equals()
+ *
+ * This test will compare lists, maps and NULL values selected from different
+ * rows (and results) of a table.
+ *
+ * @test_category queries:basic
+ * @expected_result Values are equal only if they have the same type and the same elements
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, ValueEquals) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string(
+      "CREATE TABLE %s (key int PRIMARY KEY, li list<int>, lt list<text>, m map<int, text>)",
+      table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, li, m) VALUES (0, [1, 2, 3], {3: 'c', 1: 'a'})",
+                    table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, li, m) VALUES (1, [1, 2, 3], {1: 'a', 3: 'c'})",
+                    table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, li, lt, m) VALUES (2, [3, 2, 1], ['a'], {1: 'a'})",
+                    table_name_.c_str()));
+  session_.execute(format_string("INSERT INTO %s (key) VALUES (3)", table_name_.c_str()));
+
+  Result results[4];
+  for (int i = 0; i < 4; ++i) {
+    results[i] = session_.execute(
+        format_string("SELECT li, lt, m FROM %s WHERE key = %d", table_name_.c_str(), i));
+  }
+  const CassRow* rows[4];
+  for (int i = 0; i < 4; ++i) {
+    rows[i] = cass_result_first_row(results[i].get());
+    ASSERT_TRUE(rows[i] != NULL);
+  }
+
+  // Lists are equal only with the same elements in the same order
+  EXPECT_TRUE(cass_value_equals(cass_row_get_column(rows[0], 0), cass_row_get_column(rows[1], 0)));
+  EXPECT_FALSE(cass_value_equals(cass_row_get_column(rows[0], 0), cass_row_get_column(rows[2], 0)));
+
+  // Maps are equal regardless of the order their entries were inserted in
+  EXPECT_TRUE(cass_value_equals(cass_row_get_column(rows[0], 2), cass_row_get_column(rows[1], 2)));
+  EXPECT_FALSE(cass_value_equals(cass_row_get_column(rows[0], 2), cass_row_get_column(rows[2], 2)));
+
+  // NULL is equal only to NULL of the same type
+  EXPECT_TRUE(cass_value_equals(cass_row_get_column(rows[0], 1), cass_row_get_column(rows[1], 1)));
+  EXPECT_FALSE(cass_value_equals(cass_row_get_column(rows[0], 1), cass_row_get_column(rows[2], 1)));
+  EXPECT_FALSE(cass_value_equals(cass_row_get_column(rows[3], 0), cass_row_get_column(rows[3], 1)));
+}
+
+/**
+ * Perform a select of an aggregate and read it using its result metadata type
+ *
+ * This test will ensure that a computed column (`count(*)`) is typed from the
+ * result metadata, so it can be read as a bigint.
+ *
+ * @test_category queries:basic
+ * @expected_result The count of the inserted rows is read as an int64
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, CountAsInt64) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  for (int i = 0; i < 3; ++i) {
+    std::string value = format_string("%d", i);
+    session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(),
+                                   value.c_str(), value.c_str()));
+  }
+
+  Result result = session_.execute(format_string("SELECT count(*) FROM %s", table_name_.c_str()));
+  const CassValue* value = result.first_row().next().get_value();
+  EXPECT_EQ(CASS_VALUE_TYPE_BIGINT, cass_value_type(value));
+
+  cass_int64_t count;
+  ASSERT_EQ(CASS_OK, cass_value_get_int64(value, &count));
+  EXPECT_EQ(3, count);
+}
+
+/**
+ * Perform an insert using a statement created with the query length
+ *
+ * This test will create a statement with three parameters using a query which
+ * is not null-terminated at its given length, bind all of its parameters and
+ * ensure that binding beyond the parameter count is out of bounds.
+ *
+ * @test_category queries:basic
+ * @expected_result The insert succeeds and binding the fourth parameter fails
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, StatementNewN) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, a int, b int)",
+                                 table_name_.c_str()));
+
+  std::string query =
+      format_string("INSERT INTO %s (key, a, b) VALUES (?, ?, ?)", table_name_.c_str());
+  std::string query_with_garbage = query + " garbage";
+  Statement statement(cass_statement_new_n(query_with_garbage.data(), query.size(), 3));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_int32(statement.get(), 0, 1));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_int32(statement.get(), 1, 2));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_int32(statement.get(), 2, 3));
+  EXPECT_EQ(CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS, cass_statement_bind_int32(statement.get(), 3, 4));
+  session_.execute(statement);
+
+  Result result = session_.execute(
+      format_string("SELECT a, b FROM %s WHERE key = 1", table_name_.c_str()));
+  Row row = result.first_row();
+  EXPECT_EQ(Integer(2), row.next().as<Integer>());
+  EXPECT_EQ(Integer(3), row.next().as<Integer>());
+}
+
+/**
+ * Iterate over a tuple with a null element
+ *
+ * This test will ensure that iterating over a tuple yields every position of
+ * the tuple, with a null element yielded as a null value.
+ *
+ * @test_category data_types:tuple
+ * @expected_result Three values are yielded and only the middle one is null
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, TupleNullElement) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int",
+                                 "frozen<tuple<int, int, int>>"));
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "(1, null, 3)"));
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  const CassValue* tuple = result.first_row().next().get_value();
+
+  CassIterator* iterator = cass_iterator_from_tuple(tuple);
+  ASSERT_TRUE(iterator != NULL);
+  cass_int32_t expected[] = { 1, 0, 3 };
+  size_t count = 0;
+  while (cass_iterator_next(iterator)) {
+    const CassValue* element = cass_iterator_get_value(iterator);
+    ASSERT_TRUE(element != NULL);
+    if (count == 1) {
+      EXPECT_TRUE(cass_value_is_null(element));
+    } else {
+      cass_int32_t output;
+      EXPECT_FALSE(cass_value_is_null(element));
+      ASSERT_EQ(CASS_OK, cass_value_get_int32(element, &output));
+      EXPECT_EQ(expected[count], output);
+    }
+    count++;
+  }
+  cass_iterator_free(iterator);
+  EXPECT_EQ(3u, count);
+}
+
+/**
+ * Perform an insert of a null collection bound to a prepared statement
+ *
+ * This test will bind a NULL collection pointer, which writes an explicit
+ * null (unlike an empty collection), and ensure the value is read as null.
+ *
+ * @test_category data_types:collections
+ * @expected_result The bound collection is read back as null
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindNullCollection) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "list<int>"));
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "[1, 2, 3]"));
+
+  Prepared prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+  Statement statement = prepared.bind();
+  statement.bind<Integer>(0, Integer(0));
+  ASSERT_EQ(CASS_OK, cass_statement_bind_collection(statement.get(), 1, NULL));
+  session_.execute(statement);
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  EXPECT_TRUE(cass_value_is_null(result.first_row().next().get_value()));
+}
+
+/**
+ * Verify whether results of a select and an insert are rows results
+ *
+ * @test_category queries:basic
+ * @expected_result Result of a select (even without rows) has rows, result of an insert does not
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, ResultHasRows) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  EXPECT_TRUE(cass_result_has_rows(result.get()));
+  EXPECT_EQ(0u, result.row_count());
+
+  result = session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "0"));
+  EXPECT_FALSE(cass_result_has_rows(result.get()));
+}
+
+/**
+ * Read decimals with a zero unscaled value and with a large precision
+ *
+ * This test will ensure that a zero decimal (`0.00`) is read as a single zero
+ * byte varint with its scale, and that a decimal with a precision exceeding
+ * 64 bits is read as its full varint, which is not reallocated between calls.
+ *
+ * @test_category data_types:decimal
+ * @expected_result Varints and scales of both decimals are read back
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, DecimalZeroAndLargePrecision) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "decimal"));
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "0.00"));
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "1",
+                                 "12345678901234567890.123456789"));
+
+  const cass_byte_t* varint;
+  size_t varint_size;
+  cass_int32_t scale;
+
+  { // Zero
+    Result result =
+        session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+    const CassValue* value = result.first_row().next().get_value();
+    ASSERT_EQ(CASS_OK, cass_value_get_decimal(value, &varint, &varint_size, &scale));
+    ASSERT_TRUE(varint != NULL);
+    ASSERT_EQ(1u, varint_size);
+    EXPECT_EQ(0x00, varint[0]);
+    EXPECT_EQ(2, scale);
+  }
+
+  { // Large precision
+    Result result =
+        session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "1"));
+    const CassValue* value = result.first_row().next().get_value();
+    ASSERT_EQ(CASS_OK, cass_value_get_decimal(value, &varint, &varint_size, &scale));
+    const cass_byte_t expected[] = { 0x27, 0xe4, 0x1b, 0x32, 0x46, 0xbe,
+                                     0xc9, 0xb1, 0x6e, 0x39, 0x81, 0x15 };
+    ASSERT_EQ(sizeof(expected), varint_size);
+    EXPECT_EQ(0, memcmp(expected, varint, varint_size));
+    EXPECT_EQ(9, scale);
+
+    const cass_byte_t* varint_again;
+    ASSERT_EQ(CASS_OK, cass_value_get_decimal(value, &varint_again, &varint_size, &scale));
+    EXPECT_EQ(varint, varint_again);
+  }
+}
+
+/**
+ * Bind a non-ASCII string to ascii and text columns of a prepared statement
+ *
+ * This test will ensure that a string containing non-ASCII characters is
+ * rejected when bound to an ascii column and accepted (and read back
+ * unchanged) when bound to a text column.
+ *
+ * @test_category data_types:ascii
+ * @test_category data_types:text
+ * @expected_result Binding to the ascii column fails; the text value is read back
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindNonAsciiString) {
+  CHECK_FAILURE;
+
+  const char* value = "caf\xc3\xa9";
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, a ascii, t text)",
+                                 table_name_.c_str()));
+
+  Prepared prepared = session_.prepare(
+      format_string("INSERT INTO %s (key, a, t) VALUES (?, ?, ?)", table_name_.c_str()));
+  Statement statement = prepared.bind();
+  statement.bind<Integer>(0, Integer(0));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_string(statement.get(), 1, value));
+  ASSERT_EQ(CASS_OK, cass_statement_bind_string(statement.get(), 1, "cafe"));
+  ASSERT_EQ(CASS_OK, cass_statement_bind_string(statement.get(), 2, value));
+  session_.execute(statement);
+
+  Result result = session_.execute(
+      format_string("SELECT a, t FROM %s WHERE key = 0", table_name_.c_str()));
+  Row row = result.first_row();
+  EXPECT_EQ(Ascii("cafe"), row.next().as<Ascii>());
+  EXPECT_EQ(Text(value), row.next().as<Text>());
+}
+
+/**
+ * Bind NaN and infinities to a double column of a prepared statement
+ *
+ * This test will ensure that NaN, positive infinity and negative infinity are
+ * neither rejected nor altered when bound, and that they are read back with
+ * the same bit patterns.
+ *
+ * @test_category data_types:primitive
+ * @expected_result Values are read back bit-exactly
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindDoubleNaNAndInfinity) {
+  CHECK_FAILURE;
+
+  const cass_double_t values[] = { std::numeric_limits<cass_double_t>::quiet_NaN(),
+                                   std::numeric_limits<cass_double_t>::infinity(),
+                                   -std::numeric_limits<cass_double_t>::infinity() };
+  const size_t count = sizeof(values) / sizeof(values[0]);
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "double"));
+  Prepared prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+  for (size_t i = 0; i < count; ++i) {
+    Statement statement = prepared.bind();
+    statement.bind<Integer>(0, Integer(static_cast<cass_int32_t>(i)));
+    ASSERT_EQ(CASS_OK, cass_statement_bind_double(statement.get(), 1, values[i]));
+    session_.execute(statement);
+  }
+
+  for (size_t i = 0; i < count; ++i) {
+    std::string key = format_string("%d", static_cast<int>(i));
+    Result result = session_.execute(
+        format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), key.c_str()));
+    cass_double_t value;
+    ASSERT_EQ(CASS_OK, cass_value_get_double(result.first_row().next().get_value(), &value));
+    EXPECT_EQ(0, memcmp(&values[i], &value, sizeof(value)));
+  }
+}
+
+/**
+ * Update columns with a bound null and an unset value
+ *
+ * This test will bind NULL to one column of a prepared update and leave
+ * another one unbound, and ensure that the null overwrites its column with a
+ * tombstone while the unset value leaves its column untouched.
+ *
+ * @test_category queries:prepared
+ * @expected_result The null column is read as null, the unset one keeps its value
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindNullAndUnset) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, a int, b int)",
+                                 table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, a, b) VALUES (0, 1, 2)", table_name_.c_str()));
+
+  Prepared prepared = session_.prepare(
+      format_string("UPDATE %s SET a = ?, b = ? WHERE key = ?", table_name_.c_str()));
+  Statement statement = prepared.bind();
+  ASSERT_EQ(CASS_OK, cass_statement_bind_null(statement.get(), 0));
+  statement.bind<Integer>(2, Integer(0));
+  session_.execute(statement);
+
+  Result result = session_.execute(
+      format_string("SELECT a, b FROM %s WHERE key = 0", table_name_.c_str()));
+  Row row = result.first_row();
+  EXPECT_TRUE(cass_value_is_null(row.next().get_value()));
+  EXPECT_EQ(Integer(2), row.next().as<Integer>());
+}
+
+/**
+ * Read the same rows with lazy rows disabled and enabled
+ *
+ * This test will execute the same query with and without
+ * `cass_statement_set_lazy_rows()`, read the rows of the lazy result in
+ * reverse order, and ensure that all values are equal to the eager ones.
+ *
+ * @test_category queries:basic
+ * @expected_result Values of lazy rows are identical to values of eager rows
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, LazyRowsIdenticalValues) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, t text, d decimal, "
+                                 "li list<int>, m map<text, double>)",
+                                 table_name_.c_str()));
+  const int number_of_rows = 20;
+  for (int i = 0; i < number_of_rows; ++i) {
+    session_.execute(format_string("INSERT INTO %s (key, t, d, li, m) VALUES "
+                                   "(%d, 'text %d', %d.%d, [%d, %d], {'a': %d.5, 'b': NaN})",
+                                   table_name_.c_str(), i, i, i, i, i, -i, i));
+  }
+  // Row with null values
+  session_.execute(format_string("INSERT INTO %s (key) VALUES (%d)", table_name_.c_str(),
+                                 number_of_rows));
+
+  std::string query = format_string("SELECT * FROM %s", table_name_.c_str());
+  Result eager = session_.execute(query);
+  Statement statement(query);
+  ASSERT_EQ(CASS_OK, cass_statement_set_lazy_rows(statement.get(), cass_true));
+  Result lazy = session_.execute(statement);
+  ASSERT_EQ(static_cast<size_t>(number_of_rows + 1), eager.row_count());
+  ASSERT_EQ(eager.row_count(), lazy.row_count());
+  ASSERT_EQ(eager.column_count(), lazy.column_count());
+
+  std::vector<const CassRow*> eager_rows;
+  std::vector<const CassRow*> lazy_rows;
+  CassIterator* eager_iterator = cass_iterator_from_result(eager.get());
+  CassIterator* lazy_iterator = cass_iterator_from_result(lazy.get());
+  while (cass_iterator_next(eager_iterator) && cass_iterator_next(lazy_iterator)) {
+    eager_rows.push_back(cass_iterator_get_row(eager_iterator));
+    lazy_rows.push_back(cass_iterator_get_row(lazy_iterator));
+  }
+  cass_iterator_free(eager_iterator);
+  cass_iterator_free(lazy_iterator);
+  ASSERT_EQ(eager.row_count(), lazy_rows.size());
+
+  // Access the lazy rows in reverse order, so they are built out of order
+  for (size_t i = lazy_rows.size(); i-- > 0;) {
+    for (size_t j = 0; j < lazy.column_count(); ++j) {
+      EXPECT_TRUE(cass_value_equals(cass_row_get_column(eager_rows[i], j),
+                                    cass_row_get_column(lazy_rows[i], j)))
+          << "Row " << i << ", column " << j;
+    }
+  }
+}
+
+/**
+ * Compare decimal and duration values
+ *
+ * This test will ensure that decimals are equal only with the same unscaled
+ * value and scale (so 1.0 is not equal to 1.00) and that durations are equal
+ * only with the same months, days and nanoseconds (so 1 day is not equal to
+ * 24 hours).
+ *
+ * @test_category data_types:decimal
+ * @test_category data_types:duration
+ * @expected_result Values are equal only if they are exactly the same
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, ValueEqualsDecimalAndDuration) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, d decimal, du duration)",
+                                 table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, d, du) VALUES (0, 1.0, 1d)", table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, d, du) VALUES (1, 1.0, 1d)", table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, d, du) VALUES (2, 1.00, 24h)", table_name_.c_str()));
+  session_.execute(
+      format_string("INSERT INTO %s (key, d, du) VALUES (3, 2.0, 1d1ns)", table_name_.c_str()));
+
+  Result results[4];
+  const CassRow* rows[4];
+  for (int i = 0; i < 4; ++i) {
+    results[i] = session_.execute(
+        format_string("SELECT d, du FROM %s WHERE key = %d", table_name_.c_str(), i));
+    rows[i] = cass_result_first_row(results[i].get());
+    ASSERT_TRUE(rows[i] != NULL);
+  }
+
+  for (size_t column = 0; column < 2; ++column) {
+    const CassValue* value = cass_row_get_column(rows[0], column);
+    // Exactly the same values are equal
+    EXPECT_TRUE(cass_value_equals(value, cass_row_get_column(rows[1], column)));
+    // Different scale (1.00) or units (24h)
+    EXPECT_FALSE(cass_value_equals(value, cass_row_get_column(rows[2], column)));
+    // Different magnitude (2.0) or nanoseconds (1d1ns)
+    EXPECT_FALSE(cass_value_equals(value, cass_row_get_column(rows[3], column)));
+  }
+}
+
+/**
+ * Verify whether conditional and plain inserts were applied
+ *
+ * @test_category queries:lwt
+ * @expected_result The first conditional insert and a plain insert are applied, a conditional
+ *                  insert of an existing row is not
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, ResultWasApplied) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  std::string conditional_insert =
+      format_string("INSERT INTO %s (key, value) VALUES (0, 0) IF NOT EXISTS", table_name_.c_str());
+
+  Result applied = session_.execute(conditional_insert);
+  EXPECT_TRUE(cass_result_was_applied(applied.get()));
+
+  Result not_applied = session_.execute(conditional_insert);
+  EXPECT_FALSE(cass_result_was_applied(not_applied.get()));
+
+  Result plain = session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "1", "1"));
+  EXPECT_TRUE(cass_result_was_applied(plain.get()));
+}
+
+/**
+ * Get the bytes of a value twice
+ *
+ * This test will ensure that repeated calls to `cass_value_get_bytes()` on the
+ * same value return the same pointer, both for blobs and for values which are
+ * serialized again by the driver.
+ *
+ * @test_category data_types:blob
+ * @expected_result The same pointer and size are returned by both calls
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, GetBytesStablePointer) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, b blob, i int, t text)",
+                                 table_name_.c_str()));
+  session_.execute(format_string("INSERT INTO %s (key, b, i, t) VALUES (0, 0x0102, 3, 'text')",
+                                 table_name_.c_str()));
+
+  Result result =
+      session_.execute(format_string("SELECT b, i, t FROM %s WHERE key = 0", table_name_.c_str()));
+  const CassRow* row = result.first_row().get();
+  for (size_t i = 0; i < 3; ++i) {
+    const CassValue* value = cass_row_get_column(row, i);
+    const cass_byte_t* first;
+    size_t first_size;
+    ASSERT_EQ(CASS_OK, cass_value_get_bytes(value, &first, &first_size));
+    const cass_byte_t* second;
+    size_t second_size;
+    ASSERT_EQ(CASS_OK, cass_value_get_bytes(value, &second, &second_size));
+    EXPECT_EQ(first, second) << "Column " << i;
+    EXPECT_EQ(first_size, second_size) << "Column " << i;
+  }
+}
+
+/**
+ * Bind raw bytes to a uuid column of a prepared statement
+ *
+ * This test will ensure that 16 bytes bound with `cass_statement_bind_bytes()`
+ * are read back as the equivalent UUID, and that bytes of any other length are
+ * rejected.
+ *
+ * @test_category data_types:uuid
+ * @expected_result The UUID is read back; 15 and 17 bytes are rejected
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindBytesToUuid) {
+  CHECK_FAILURE;
+
+  const cass_byte_t bytes[] = { 0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7,
+                                0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00, 0x01 };
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "uuid"));
+  Prepared prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+  Statement statement = prepared.bind();
+  statement.bind<Integer>(0, Integer(0));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_bytes(statement.get(), 1, bytes, 15));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_bytes(statement.get(), 1, bytes, 17));
+  ASSERT_EQ(CASS_OK, cass_statement_bind_bytes(statement.get(), 1, bytes, 16));
+  session_.execute(statement);
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  CassUuid expected;
+  ASSERT_EQ(CASS_OK, cass_uuid_from_string("550e8400-e29b-41d4-a716-446655440000", &expected));
+  EXPECT_EQ(Uuid(expected), result.first_row().next().as<Uuid>());
+}
+
+/**
+ * Get the first row of void and rows results
+ *
+ * This test will ensure that `cass_result_first_row()` returns NULL for a void
+ * result and for a rows result without rows, and the first row (the same as
+ * the first row of the result iterator) for results with one and many rows.
+ *
+ * @test_category queries:basic
+ * @expected_result First row is NULL only for results without rows
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, FirstRow) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+
+  { // Void
+    Result result = session_.execute(
+        format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "0"));
+    EXPECT_FALSE(cass_result_has_rows(result.get()));
+    EXPECT_TRUE(cass_result_first_row(result.get()) == NULL);
+  }
+
+  { // Empty rows
+    Result result =
+        session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "1"));
+    EXPECT_TRUE(cass_result_has_rows(result.get()));
+    EXPECT_EQ(0u, result.row_count());
+    EXPECT_TRUE(cass_result_first_row(result.get()) == NULL);
+  }
+
+  { // One row
+    Result result =
+        session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+    ASSERT_EQ(1u, result.row_count());
+    const CassRow* row = cass_result_first_row(result.get());
+    ASSERT_TRUE(row != NULL);
+    cass_int32_t value;
+    ASSERT_EQ(CASS_OK, cass_value_get_int32(cass_row_get_column(row, 0), &value));
+    EXPECT_EQ(0, value);
+  }
+
+  { // Many rows
+    for (int i = 1; i < 5; ++i) {
+      std::string value = format_string("%d", i);
+      session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(),
+                                     value.c_str(), value.c_str()));
+    }
+    Result result = session_.execute(default_select_all());
+    ASSERT_EQ(5u, result.row_count());
+    const CassRow* row = cass_result_first_row(result.get());
+    ASSERT_TRUE(row != NULL);
+
+    CassIterator* iterator = cass_iterator_from_result(result.get());
+    ASSERT_TRUE(cass_iterator_next(iterator));
+    EXPECT_EQ(cass_iterator_get_row(iterator), row);
+    cass_iterator_free(iterator);
+  }
+}
+
+/**
+ * Get a boolean and an integer value with the getter of the other type
+ *
+ * This test will ensure that `cass_value_get_bool()` rejects an int value and
+ * that the integer getters reject a boolean value.
+ *
+ * @test_category basic
+ * @expected_result Getters of the other type return CASS_ERROR_LIB_INVALID_VALUE_TYPE
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, GetBoolAndIntStrictness) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string("CREATE TABLE %s (key int PRIMARY KEY, flag boolean)", table_name_.c_str()));
+  session_.execute(format_string("INSERT INTO %s (key, flag) VALUES (1, true)",
+                                 table_name_.c_str()));
+
+  Result result = session_.execute(
+      format_string("SELECT key, flag FROM %s WHERE key = 1", table_name_.c_str()));
+  const CassRow* row = cass_result_first_row(result.get());
+  ASSERT_TRUE(row != NULL);
+  const CassValue* int_value = cass_row_get_column(row, 0);
+  const CassValue* bool_value = cass_row_get_column(row, 1);
+
+  cass_bool_t b = cass_false;
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_value_get_bool(int_value, &b));
+  EXPECT_EQ(CASS_OK, cass_value_get_bool(bool_value, &b));
+  EXPECT_EQ(cass_true, b);
+
+  cass_int8_t i8;
+  cass_int16_t i16;
+  cass_int32_t i32 = 0;
+  cass_int64_t i64;
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_value_get_int8(bool_value, &i8));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_value_get_int16(bool_value, &i16));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_value_get_int32(bool_value, &i32));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_value_get_int64(bool_value, &i64));
+  EXPECT_EQ(CASS_OK, cass_value_get_int32(int_value, &i32));
+  EXPECT_EQ(1, i32);
+}
+
+/**
+ * Bind int64 values to timestamp and time columns of a prepared statement
+ *
+ * This test will ensure that milliseconds since epoch bound with
+ * `cass_statement_bind_int64()` to a timestamp column, and nanoseconds since
+ * midnight bound to a time column, are read back unchanged with
+ * `cass_value_get_int64()`.
+ *
+ * @test_category data_types:timestamp
+ * @expected_result The bound values are read back unchanged
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindInt64ToTimestampAndTime) {
+  CHECK_FAILURE;
+
+  const cass_int64_t millis = 1577836800123LL;
+  const cass_int64_t nanos = 45296789000001LL;
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, ts timestamp, t time)",
+                                 table_name_.c_str()));
+  Prepared prepared = session_.prepare(
+      format_string("INSERT INTO %s (key, ts, t) VALUES (0, ?, ?)", table_name_.c_str()));
+  Statement statement = prepared.bind();
+  ASSERT_EQ(CASS_OK, cass_statement_bind_int64(statement.get(), 0, millis));
+  ASSERT_EQ(CASS_OK, cass_statement_bind_int64(statement.get(), 1, nanos));
+  session_.execute(statement);
+
+  Result result =
+      session_.execute(format_string("SELECT ts, t FROM %s WHERE key = 0", table_name_.c_str()));
+  const CassRow* row = cass_result_first_row(result.get());
+  ASSERT_TRUE(row != NULL);
+  cass_int64_t value = 0;
+  ASSERT_EQ(CASS_OK, cass_value_get_int64(cass_row_get_column(row, 0), &value));
+  EXPECT_EQ(millis, value);
+  ASSERT_EQ(CASS_OK, cass_value_get_int64(cass_row_get_column(row, 1), &value));
+  EXPECT_EQ(nanos, value);
+}
+
+/**
+ * Get the data type of a collection
+ *
+ * This test will ensure that `cass_collection_data_type()` returns the data
+ * type a collection was created from, and a type reflecting the kind of the
+ * collection for collections created without a data type.
+ *
+ * @test_category data_types:collections
+ * @expected_result The data type of the collection is returned
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, CollectionDataType) {
+  CHECK_FAILURE;
+
+  CassDataType* list_type = cass_data_type_new(CASS_VALUE_TYPE_LIST);
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_value_type(list_type, CASS_VALUE_TYPE_INT));
+  CassCollection* typed = cass_collection_new_from_data_type(list_type, 2);
+  const CassDataType* data_type = cass_collection_data_type(typed);
+  ASSERT_TRUE(data_type != NULL);
+  EXPECT_EQ(CASS_VALUE_TYPE_LIST, cass_data_type_type(data_type));
+  EXPECT_EQ(1u, cass_data_type_sub_type_count(data_type));
+  EXPECT_EQ(CASS_VALUE_TYPE_INT,
+            cass_data_type_type(cass_data_type_sub_data_type(data_type, 0)));
+  cass_collection_free(typed);
+  cass_data_type_free(list_type);
+
+  CassCollection* untyped = cass_collection_new(CASS_COLLECTION_TYPE_MAP, 2);
+  data_type = cass_collection_data_type(untyped);
+  ASSERT_TRUE(data_type != NULL);
+  EXPECT_EQ(CASS_VALUE_TYPE_MAP, cass_data_type_type(data_type));
+  EXPECT_EQ(0u, cass_data_type_sub_type_count(data_type));
+
+  // The type of an untyped collection can be used to create another collection.
+  CassCollection* from_untyped = cass_collection_new_from_data_type(data_type, 1);
+  ASSERT_TRUE(from_untyped != NULL);
+  EXPECT_EQ(CASS_OK, cass_collection_append_int32(from_untyped, 1));
+  EXPECT_EQ(CASS_OK, cass_collection_append_string(from_untyped, "a"));
+  cass_collection_free(untyped);
+  EXPECT_EQ(CASS_VALUE_TYPE_MAP, cass_data_type_type(cass_collection_data_type(from_untyped)));
+  cass_collection_free(from_untyped);
+}
+
+/**
+ * Set a nested tuple into a typed tuple
+ *
+ * This test will build a `tuple<int, tuple<text, text>>` value, ensure that a
+ * nested tuple of another declared type is rejected, and that both the value
+ * and the nested data type reported by `cass_tuple_data_type()` are correct.
+ *
+ * @test_category data_types:tuple
+ * @expected_result The nested tuple is written and read back, its type is reported
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, NestedTuple) {
+  CHECK_FAILURE;
+
+  CassDataType* inner_type = cass_data_type_new_tuple(2);
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_value_type(inner_type, CASS_VALUE_TYPE_TEXT));
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_value_type(inner_type, CASS_VALUE_TYPE_TEXT));
+  CassDataType* outer_type = cass_data_type_new_tuple(2);
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_value_type(outer_type, CASS_VALUE_TYPE_INT));
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_type(outer_type, inner_type));
+  CassDataType* wrong_type = cass_data_type_new_tuple(2);
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_value_type(wrong_type, CASS_VALUE_TYPE_INT));
+  ASSERT_EQ(CASS_OK, cass_data_type_add_sub_value_type(wrong_type, CASS_VALUE_TYPE_INT));
+
+  CassTuple* inner = cass_tuple_new_from_data_type(inner_type);
+  ASSERT_EQ(CASS_OK, cass_tuple_set_string(inner, 0, "a"));
+  ASSERT_EQ(CASS_OK, cass_tuple_set_string(inner, 1, "b"));
+  CassTuple* wrong = cass_tuple_new_from_data_type(wrong_type);
+  CassTuple* outer = cass_tuple_new_from_data_type(outer_type);
+  ASSERT_EQ(CASS_OK, cass_tuple_set_int32(outer, 0, 1));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_tuple_set_tuple(outer, 1, wrong));
+  ASSERT_EQ(CASS_OK, cass_tuple_set_tuple(outer, 1, inner));
+
+  const CassDataType* data_type = cass_tuple_data_type(outer);
+  ASSERT_EQ(2u, cass_data_type_sub_type_count(data_type));
+  const CassDataType* nested_type = cass_data_type_sub_data_type(data_type, 1);
+  EXPECT_EQ(CASS_VALUE_TYPE_TUPLE, cass_data_type_type(nested_type));
+  ASSERT_EQ(2u, cass_data_type_sub_type_count(nested_type));
+  EXPECT_EQ(CASS_VALUE_TYPE_TEXT,
+            cass_data_type_type(cass_data_type_sub_data_type(nested_type, 0)));
+  EXPECT_EQ(CASS_VALUE_TYPE_TEXT,
+            cass_data_type_type(cass_data_type_sub_data_type(nested_type, 1)));
+
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int",
+                                 "frozen<tuple<int, frozen<tuple<text, text>>>>"));
+  Statement statement(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "?"), 1);
+  ASSERT_EQ(CASS_OK, cass_statement_bind_tuple(statement.get(), 0, outer));
+  session_.execute(statement);
+  cass_tuple_free(outer);
+  cass_tuple_free(wrong);
+  cass_tuple_free(inner);
+  cass_data_type_free(wrong_type);
+  cass_data_type_free(outer_type);
+  cass_data_type_free(inner_type);
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  CassIterator* iterator = cass_iterator_from_tuple(result.first_row().next().get_value());
+  ASSERT_TRUE(iterator != NULL);
+  ASSERT_TRUE(cass_iterator_next(iterator));
+  cass_int32_t number = 0;
+  EXPECT_EQ(CASS_OK, cass_value_get_int32(cass_iterator_get_value(iterator), &number));
+  EXPECT_EQ(1, number);
+  ASSERT_TRUE(cass_iterator_next(iterator));
+  CassIterator* nested = cass_iterator_from_tuple(cass_iterator_get_value(iterator));
+  ASSERT_TRUE(nested != NULL);
+  const char* expected[] = { "a", "b" };
+  for (size_t i = 0; i < 2; ++i) {
+    ASSERT_TRUE(cass_iterator_next(nested));
+    const char* text;
+    size_t text_length;
+    ASSERT_EQ(CASS_OK,
+              cass_value_get_string(cass_iterator_get_value(nested), &text, &text_length));
+    EXPECT_EQ(expected[i], std::string(text, text_length));
+  }
+  EXPECT_FALSE(cass_iterator_next(nested));
+  cass_iterator_free(nested);
+  EXPECT_FALSE(cass_iterator_next(iterator));
+  cass_iterator_free(iterator);
+}
+
+/**
+ * Set an empty paging state token
+ *
+ * This test will ensure that a paging state token with a valid pointer and a
+ * size of 0 clears the paging state, so the next execution starts from the
+ * first page.
+ *
+ * @test_category queries:paging
+ * @expected_result The first page is returned again
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, EmptyPagingStateToken) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  for (int i = 0; i < 10; ++i) {
+    std::string value = format_string("%d", i);
+    session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(),
+                                   value.c_str(), value.c_str()));
+  }
+
+  Statement statement(default_select_all());
+  statement.set_paging_size(3);
+  Result first_page = session_.execute(statement);
+  ASSERT_TRUE(first_page.has_more_pages());
+  Integer first_key = first_page.first_row().next().as<Integer>();
+
+  statement.set_paging_state_token(first_page.paging_state_token());
+  Result second_page = session_.execute(statement);
+  EXPECT_NE(first_key, second_page.first_row().next().as<Integer>());
+
+  const char token[] = "";
+  ASSERT_EQ(CASS_OK, cass_statement_set_paging_state_token(statement.get(), token, 0));
+  Result result = session_.execute(statement);
+  EXPECT_EQ(3u, result.row_count());
+  EXPECT_EQ(first_key, result.first_row().next().as<Integer>());
+}
+
+/**
+ * Get the components of a time value
+ *
+ * This test will ensure that `cass_value_get_time_components()` splits a
+ * known time of day into hours, minutes, seconds and nanoseconds, and that it
+ * rejects values of other types.
+ *
+ * @test_category data_types:time
+ * @expected_result The components of the time of day are returned
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, TimeComponents) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, t time)",
+                                 table_name_.c_str()));
+  session_.execute(format_string("INSERT INTO %s (key, t) VALUES (0, '13:45:30.123456789')",
+                                 table_name_.c_str()));
+
+  Result result =
+      session_.execute(format_string("SELECT key, t FROM %s WHERE key = 0", table_name_.c_str()));
+  const CassRow* row = cass_result_first_row(result.get());
+  ASSERT_TRUE(row != NULL);
+  cass_int32_t hours, minutes, seconds, nanos;
+  ASSERT_EQ(CASS_OK, cass_value_get_time_components(cass_row_get_column(row, 1), &hours,
+                                                    &minutes, &seconds, &nanos));
+  EXPECT_EQ(13, hours);
+  EXPECT_EQ(45, minutes);
+  EXPECT_EQ(30, seconds);
+  EXPECT_EQ(123456789, nanos);
+
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_value_get_time_components(cass_row_get_column(row, 0), &hours, &minutes,
+                                           &seconds, &nanos));
+}
+
+/**
+ * Execute a prepared statement with an unset partition key
+ *
+ * This test will prepare an insert with two bind markers, bind only the value
+ * and ensure that the statement is rejected by the driver before it is sent,
+ * because its partition key is unset.
+ *
+ * @test_category error_codes
+ * @test_category queries:prepared
+ * @expected_result CASS_ERROR_LIB_PARAMETER_UNSET is returned and nothing is inserted
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, UnsetPartitionKey) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  Prepared insert_prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+
+  Statement insert_statement = insert_prepared.bind();
+  insert_statement.bind<Integer>(1, Integer(1));
+  Result result = session_.execute(insert_statement, false);
+  EXPECT_EQ(CASS_ERROR_LIB_PARAMETER_UNSET, result.error_code());
+  EXPECT_TRUE(contains(result.error_message(), "Partition key column 'key' is unset"));
+
+  EXPECT_TRUE(session_.execute(default_select_all()).is_empty());
+}
+
+/**
+ * Bind raw bytes to a decimal column of a prepared statement
+ *
+ * This test will ensure that the serialized form of a decimal (4-byte scale
+ * followed by the unscaled varint) bound with `cass_statement_bind_bytes()` is
+ * read back with `cass_value_get_decimal()`, and that too short bytes are
+ * rejected.
+ *
+ * @test_category data_types:decimal
+ * @expected_result The decimal is read back; 4 bytes are rejected
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindBytesToDecimal) {
+  CHECK_FAILURE;
+
+  // 12.345 - unscaled value 12345 with scale 3
+  const cass_byte_t bytes[] = { 0x00, 0x00, 0x00, 0x03, 0x30, 0x39 };
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "decimal"));
+  Prepared prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+  Statement statement = prepared.bind();
+  statement.bind<Integer>(0, Integer(0));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_bytes(statement.get(), 1, bytes, 4));
+  ASSERT_EQ(CASS_OK, cass_statement_bind_bytes(statement.get(), 1, bytes, sizeof(bytes)));
+  session_.execute(statement);
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  const cass_byte_t* varint;
+  size_t varint_size;
+  cass_int32_t scale;
+  ASSERT_EQ(CASS_OK, cass_value_get_decimal(result.first_row().next().get_value(), &varint,
+                                            &varint_size, &scale));
+  EXPECT_EQ(3, scale);
+  ASSERT_EQ(2u, varint_size);
+  EXPECT_EQ(0x30, varint[0]);
+  EXPECT_EQ(0x39, varint[1]);
+}
+
+/**
+ * Iterate over a set inserted out of order
+ *
+ * This test will insert the elements of a `set<int>` out of order and ensure
+ * that iterating over the set yields the elements sorted, as sent by the
+ * server.
+ *
+ * @test_category data_types:collections
+ * @expected_result The elements are yielded in ascending order
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, SetIterationOrder) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "set<int>"));
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0",
+                                 "{42, -7, 3, 100, 0}"));
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  CassIterator* iterator = cass_iterator_from_collection(result.first_row().next().get_value());
+  ASSERT_TRUE(iterator != NULL);
+  cass_int32_t expected[] = { -7, 0, 3, 42, 100 };
+  size_t count = 0;
+  while (cass_iterator_next(iterator)) {
+    cass_int32_t output;
+    ASSERT_EQ(CASS_OK, cass_value_get_int32(cass_iterator_get_value(iterator), &output));
+    ASSERT_LT(count, 5u);
+    EXPECT_EQ(expected[count], output);
+    count++;
+  }
+  cass_iterator_free(iterator);
+  EXPECT_EQ(5u, count);
+}
+
+/**
+ * Get the size of the bytes of values
+ *
+ * This test will ensure that `cass_value_bytes_size()` returns the size of the
+ * bytes returned by `cass_value_get_bytes()` for blob, int and text values,
+ * and 0 for a null value.
+ *
+ * @test_category basic
+ * @expected_result The sizes of the serialized values are returned
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, ValueBytesSize) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, b blob, t text, n int)",
+                                 table_name_.c_str()));
+  session_.execute(format_string("INSERT INTO %s (key, b, t) VALUES (1, 0xcafebabe01, 'hello')",
+                                 table_name_.c_str()));
+
+  Result result = session_.execute(
+      format_string("SELECT b, key, t, n FROM %s WHERE key = 1", table_name_.c_str()));
+  const CassRow* row = cass_result_first_row(result.get());
+  ASSERT_TRUE(row != NULL);
+  size_t expected[] = { 5u, 4u, 5u };
+  for (size_t i = 0; i < 3; ++i) {
+    const CassValue* value = cass_row_get_column(row, i);
+    const cass_byte_t* bytes;
+    size_t bytes_size;
+    ASSERT_EQ(CASS_OK, cass_value_get_bytes(value, &bytes, &bytes_size)) << "Column " << i;
+    EXPECT_EQ(expected[i], bytes_size) << "Column " << i;
+    EXPECT_EQ(expected[i], cass_value_bytes_size(value)) << "Column " << i;
+  }
+  EXPECT_EQ(0u, cass_value_bytes_size(cass_row_get_column(row, 3)));
+}
+
+/**
+ * Bind a collection of another kind by name to a prepared statement
+ *
+ * This test will ensure that a `set<int>` bound by name to a `list<int>`
+ * column is rejected, and that a list with elements of another type is
+ * rejected as well.
+ *
+ * @test_category data_types:collections
+ * @test_category queries:prepared
+ * @expected_result Mismatched collections are rejected; a `list<int>` is accepted
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, BindCollectionKindByName) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "list<int>"));
+  Prepared prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+  Statement statement = prepared.bind();
+
+  CassCollection* set = cass_collection_new(CASS_COLLECTION_TYPE_SET, 1);
+  ASSERT_EQ(CASS_OK, cass_collection_append_int32(set, 1));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_collection_by_name(statement.get(), "value", set));
+  cass_collection_free(set);
+
+  CassCollection* text_list = cass_collection_new(CASS_COLLECTION_TYPE_LIST, 1);
+  ASSERT_EQ(CASS_OK, cass_collection_append_string(text_list, "1"));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_collection_by_name(statement.get(), "value", text_list));
+  cass_collection_free(text_list);
+
+  CassCollection* list = cass_collection_new(CASS_COLLECTION_TYPE_LIST, 1);
+  ASSERT_EQ(CASS_OK, cass_collection_append_int32(list, 1));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_collection_by_name(statement.get(), "value", list));
+  cass_collection_free(list);
+}
+
+/**
+ * Get the bytes of values of various types
+ *
+ * This test will ensure that `cass_value_get_bytes()` returns the CQL wire
+ * representation of int, text, uuid, timestamp, list and map values.
+ *
+ * @test_category basic
+ * @expected_result The known serialized forms of the values are returned
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, GetBytesWireFormat) {
+  CHECK_FAILURE;
+
+  session_.execute(format_string("CREATE TABLE %s (key int PRIMARY KEY, i int, t text, u uuid, "
+                                 "ts timestamp, l list<int>, m map<text, int>)",
+                                 table_name_.c_str()));
+  session_.execute(format_string(
+      "INSERT INTO %s (key, i, t, u, ts, l, m) VALUES (0, 258, 'abc', "
+      "550e8400-e29b-41d4-a716-446655440000, 1000, [1, 2], {'a': 1})",
+      table_name_.c_str()));
+
+  const cass_byte_t int_bytes[] = { 0x00, 0x00, 0x01, 0x02 };
+  const cass_byte_t text_bytes[] = { 0x61, 0x62, 0x63 };
+  const cass_byte_t uuid_bytes[] = { 0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4,
+                                     0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00 };
+  const cass_byte_t timestamp_bytes[] = { 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe8 };
+  const cass_byte_t list_bytes[] = { 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04,
+                                     0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04,
+                                     0x00, 0x00, 0x00, 0x02 };
+  const cass_byte_t map_bytes[] = { 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x61,
+                                    0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01 };
+  std::vector<std::vector<cass_byte_t> > expected;
+  expected.push_back(std::vector<cass_byte_t>(int_bytes, int_bytes + sizeof(int_bytes)));
+  expected.push_back(std::vector<cass_byte_t>(text_bytes, text_bytes + sizeof(text_bytes)));
+  expected.push_back(std::vector<cass_byte_t>(uuid_bytes, uuid_bytes + sizeof(uuid_bytes)));
+  expected.push_back(
+      std::vector<cass_byte_t>(timestamp_bytes, timestamp_bytes + sizeof(timestamp_bytes)));
+  expected.push_back(std::vector<cass_byte_t>(list_bytes, list_bytes + sizeof(list_bytes)));
+  expected.push_back(std::vector<cass_byte_t>(map_bytes, map_bytes + sizeof(map_bytes)));
+
+  Result result = session_.execute(
+      format_string("SELECT i, t, u, ts, l, m FROM %s WHERE key = 0", table_name_.c_str()));
+  const CassRow* row = cass_result_first_row(result.get());
+  ASSERT_TRUE(row != NULL);
+  for (size_t i = 0; i < expected.size(); ++i) {
+    const cass_byte_t* bytes;
+    size_t bytes_size;
+    ASSERT_EQ(CASS_OK, cass_value_get_bytes(cass_row_get_column(row, i), &bytes, &bytes_size))
+        << "Column " << i;
+    EXPECT_EQ(expected[i], std::vector<cass_byte_t>(bytes, bytes + bytes_size)) << "Column " << i;
+  }
+}
+
+/**
+ * Execute requests failing on the server with distinct errors
+ *
+ * This test will ensure that errors returned by the server are reported with
+ * their specific `CASS_ERROR_SERVER_*` codes.
+ *
+ * @test_category error_codes
+ * @expected_result Each error is reported with its specific code
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, ServerErrorCodes) {
+  CHECK_FAILURE;
+
+  std::string create_table =
+      format_string("CREATE TABLE %s (key int PRIMARY KEY, value int)", table_name_.c_str());
+  session_.execute(create_table);
+
+  EXPECT_EQ(CASS_ERROR_SERVER_SYNTAX_ERROR,
+            session_.execute("SELEKT * FROM system.local", CASS_CONSISTENCY_ONE, false, false)
+                .error_code());
+  EXPECT_EQ(CASS_ERROR_SERVER_ALREADY_EXISTS,
+            session_.execute(create_table, CASS_CONSISTENCY_ONE, false, false).error_code());
+  EXPECT_EQ(CASS_ERROR_SERVER_INVALID_QUERY,
+            session_
+                .execute(format_string("SELECT missing FROM %s", table_name_.c_str()),
+                         CASS_CONSISTENCY_ONE, false, false)
+                .error_code());
+
+  // A single node cannot satisfy consistency THREE
+  Statement insert(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "1", "1"));
+  insert.set_consistency(CASS_CONSISTENCY_THREE);
+  EXPECT_EQ(CASS_ERROR_SERVER_UNAVAILABLE, session_.execute(insert, false).error_code());
+}
+
+/**
+ * Get small and huge varint values
+ *
+ * This test will ensure that a varint which fits in int64 is returned by
+ * `cass_value_get_int64()`, and that a huge one is rejected by it, but its
+ * bytes are available with `cass_value_get_bytes()`.
+ *
+ * @test_category data_types:varint
+ * @expected_result The small varint is returned as int64; the huge one only as bytes
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, GetVarint) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "varint"));
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "0", "-12345"));
+  // 2^70
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "1",
+                                 "1180591620717411303424"));
+
+  Result result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "0"));
+  const CassValue* small = result.first_row().next().get_value();
+  EXPECT_EQ(CASS_VALUE_TYPE_VARINT, cass_value_type(small));
+  cass_int64_t output = 0;
+  ASSERT_EQ(CASS_OK, cass_value_get_int64(small, &output));
+  EXPECT_EQ(-12345, output);
+
+  result =
+      session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "1"));
+  const CassValue* huge = result.first_row().next().get_value();
+  EXPECT_EQ(CASS_VALUE_TYPE_VARINT, cass_value_type(huge));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE, cass_value_get_int64(huge, &output));
+  const cass_byte_t* bytes;
+  size_t bytes_size;
+  ASSERT_EQ(CASS_OK, cass_value_get_bytes(huge, &bytes, &bytes_size));
+  ASSERT_EQ(9u, bytes_size);
+  EXPECT_EQ(0x40, bytes[0]);
+  for (size_t i = 1; i < bytes_size; ++i) {
+    EXPECT_EQ(0x00, bytes[i]) << "Byte " << i;
+  }
+}
+
+/**
+ * Select an aliased expression
+ *
+ * This test will select `ttl(value) AS t` and ensure that the column is
+ * accessible by its alias and is typed as an int, as reported by the server.
+ *
+ * @test_category basic
+ * @expected_result The TTL is read as an int32 and the reported type is int
+ */
+CASSANDRA_INTEGRATION_TEST_F(BasicsTests, AliasedExpressionType) {
+  CHECK_FAILURE;
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "text"));
+  session_.execute(format_string("INSERT INTO %s (key, value) VALUES (0, 'a') USING TTL 1000",
+                                 table_name_.c_str()));
+
+  Result result = session_.execute(
+      format_string("SELECT ttl(value) AS t FROM %s WHERE key = 0", table_name_.c_str()));
+  ASSERT_EQ(1u, result.column_count());
+  EXPECT_EQ("t", result.column_names()[0]);
+  EXPECT_EQ(CASS_VALUE_TYPE_INT, cass_result_column_type(result.get(), 0));
+
+  const CassRow* row = cass_result_first_row(result.get());
+  ASSERT_TRUE(row != NULL);
+  const CassValue* value = cass_row_get_column_by_name(row, "t");
+  ASSERT_TRUE(value != NULL);
+  EXPECT_EQ(CASS_VALUE_TYPE_INT, cass_data_type_type(cass_value_data_type(value)));
+  cass_int32_t ttl = 0;
+  ASSERT_EQ(CASS_OK, cass_value_get_int32(value, &ttl));
+  EXPECT_GT(ttl, 0);
+  EXPECT_LE(ttl, 1000);
+}
@@ -324,3 +324,186 @@ CASSANDRA_INTEGRATION_TEST_F(BatchCounterThreeNodeClusterTests, MixedPreparedAnd
     ASSERT_EQ(key.value(), value.value());
   }
 }
+
+/**
+ * Perform batch execution using statements with a stronger consistency than
+ * the batch
+ *
+ * This test will add statements set to QUORUM to a LOCAL_ONE batch and
+ * execute it against a keyspace with a replication factor that is larger than
+ * the number of nodes. The QUORUM statements are unavailable on their own so
+ * the batch can only succeed when it executes at its own consistency.
+ *
+ * @test_category queries:batch
+ * @test_category consistency
+ * @expected_result Batch is executed at LOCAL_ONE and the inserts are validated
+ */
+CASSANDRA_INTEGRATION_TEST_F(BatchSingleNodeClusterTests, BatchConsistencyIsAuthoritative) {
+  CHECK_FAILURE;
+
+  // Create a keyspace where QUORUM requires replicas that do not exist
+  std::string keyspace_name = keyspace_name_ + "_rf3";
+  std::string table_name = keyspace_name + "." + table_name_;
+  session_.execute(
+      format_string("CREATE KEYSPACE %s WITH replication = "
+                    "{ 'class': 'SimpleStrategy', 'replication_factor': 3 }",
+                    keyspace_name.c_str()));
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name.c_str(), "int",
+                                 value_cql_data_type_.c_str()));
+  std::string insert_query =
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name.c_str(), "?", "?");
+
+  // Ensure a QUORUM statement is unavailable on its own
+  Statement quorum_statement(insert_query, 2);
+  quorum_statement.bind<Integer>(0, Integer(0));
+  quorum_statement.bind<Text>(1, Text("test data 0"));
+  quorum_statement.set_consistency(CASS_CONSISTENCY_QUORUM);
+  ASSERT_EQ(CASS_ERROR_SERVER_UNAVAILABLE,
+            session_.execute(quorum_statement, false).error_code());
+
+  // Add the QUORUM statements into a LOCAL_ONE batch
+  Prepared prepared_insert = session_.prepare(insert_query);
+  Batch batch(CASS_BATCH_TYPE_UNLOGGED);
+  batch.set_consistency(CASS_CONSISTENCY_LOCAL_ONE);
+  int number_of_rows = 4;
+  for (int i = 0; i < number_of_rows; ++i) {
+    Statement statement = prepared_insert.bind();
+    statement.bind<Integer>(0, Integer(i));
+    statement.bind<Text>(1, Text(format_string("test data %d", i)));
+    statement.set_consistency(CASS_CONSISTENCY_QUORUM);
+    batch.add(statement);
+  }
+
+  // Execute the batch statement and validate the inserts
+  session_.execute(batch);
+  Result result = session_.execute("SELECT key, value FROM " + table_name);
+  ASSERT_EQ(number_of_rows, result.row_count());
+
+  session_.execute("DROP KEYSPACE " + keyspace_name);
+}
+
+/**
+ * Perform counter increments bound as int64 values using a counter batch
+ *
+ * This test will bind positive and negative deltas with
+ * `cass_statement_bind_int64()` to a prepared counter update, execute them in
+ * a counter batch and ensure the resulting counter is read back with
+ * `cass_value_get_int64()`.
+ *
+ * @test_category queries:batch
+ * @test_category data_types:counter
+ * @expected_result The counter is the sum of the bound deltas
+ */
+CASSANDRA_INTEGRATION_TEST_F(BatchCounterSingleNodeClusterTests, BindInt64Deltas) {
+  CHECK_FAILURE;
+
+  Prepared prepared_update = session_.prepare(update_query_);
+  Batch batch(CASS_BATCH_TYPE_COUNTER);
+  const cass_int64_t deltas[] = { 5, -2, 4294967296LL };
+  for (size_t i = 0; i < sizeof(deltas) / sizeof(deltas[0]); ++i) {
+    Statement statement = prepared_update.bind();
+    ASSERT_EQ(CASS_OK, cass_statement_bind_int64(statement.get(), 0, deltas[i]));
+    statement.bind<Integer>(1, Integer(0));
+    batch.add(statement);
+  }
+  session_.execute(batch);
+
+  Statement statement = select_prepared_.bind();
+  statement.bind<Integer>(0, Integer(0));
+  Result result = session_.execute(statement);
+  cass_int64_t value;
+  ASSERT_EQ(CASS_OK, cass_value_get_int64(result.first_row().next().get_value(), &value));
+  EXPECT_EQ(4294967299LL, value);
+}
+
+/**
+ * Batch (statement) integration tests for retrying write timeouts
+ *
+ * Cluster with two nodes
+ */
+class BatchRetryTwoNodeClusterTests : public Integration {
+public:
+  BatchRetryTwoNodeClusterTests() {
+    number_dc1_nodes_ = 2;
+    replication_factor_ = 2;
+    is_session_requested_ = false;
+  }
+
+  /**
+   * Execute the batch and get the time it took to fail
+   *
+   * @param batch Batch to execute
+   * @return Time in milliseconds the batch took to fail with a write timeout
+   */
+  uint64_t execute_timed_out(Batch batch) {
+    start_timer();
+    Result result = session_.execute(batch, false);
+    EXPECT_EQ(CASS_ERROR_SERVER_WRITE_TIMEOUT, result.error_code());
+    return elapsed_time();
+  }
+};
+
+/**
+ * Perform logged batches while the only batch log replica does not respond
+ *
+ * This test will find a partition key for which the first node is the first
+ * replica, so it coordinates the batch, and pause the second node, which the
+ * batch log is written to. The default retry policy retries such write
+ * timeouts only if the batch is idempotent.
+ *
+ * @test_category queries:batch
+ * @test_category retry_policy
+ * @expected_result The idempotent batch fails after being retried, so it takes longer.
+ */
+CASSANDRA_INTEGRATION_TEST_F(BatchRetryTwoNodeClusterTests, BatchLogWriteTimeoutIdempotence) {
+  CHECK_FAILURE;
+  is_test_chaotic_ = true; // Destroy the cluster after the test completes
+
+  cluster_ = default_cluster().with_load_balance_round_robin().with_request_timeout(30000);
+  cass_cluster_set_token_aware_routing(cluster_.get(), cass_true);
+  cass_cluster_set_token_aware_routing_shuffle_replicas(cluster_.get(), cass_false);
+  connect(cluster_);
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  Prepared insert = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+
+  // Find a partition key coordinated by the first node
+  std::string first_node = ccm_->get_ip_prefix() + "1";
+  int key = -1;
+  for (int i = 0; i < 100 && key < 0; ++i) {
+    Statement statement = insert.bind();
+    statement.bind<Integer>(0, Integer(i));
+    statement.bind<Integer>(1, Integer(i));
+    statement.set_tracing(true);
+    Uuid tracing_id = session_.execute(statement).tracing_id();
+    Future future(cass_session_get_trace(session_.get(), tracing_id.value()));
+    future.wait();
+
+    Statement select("SELECT coordinator FROM system_traces.sessions WHERE session_id = ?", 1);
+    select.bind<Uuid>(0, tracing_id);
+    if (session_.execute(select).first_row().next().as<Inet>().str() == first_node) {
+      key = i;
+    }
+  }
+  ASSERT_GE(key, 0) << "No partition key is coordinated by the first node";
+
+  Batch non_idempotent(CASS_BATCH_TYPE_LOGGED);
+  Batch idempotent(CASS_BATCH_TYPE_LOGGED);
+  idempotent.set_idempotent(true);
+  for (int i = 0; i < 2; ++i) {
+    Batch& batch = i == 0 ? non_idempotent : idempotent;
+    Statement statement = insert.bind();
+    statement.bind<Integer>(0, Integer(key));
+    statement.bind<Integer>(1, Integer(i));
+    batch.add(statement);
+    batch.set_consistency(CASS_CONSISTENCY_ONE);
+  }
+
+  pause_node(2);
+  uint64_t non_idempotent_time = execute_timed_out(non_idempotent);
+  uint64_t idempotent_time = execute_timed_out(idempotent);
+  resume_node(2);
+
+  EXPECT_GT(idempotent_time, non_idempotent_time * 3 / 2);
+}
@@ -15,6 +15,12 @@
 */
 
 #include "integration.hpp"
+#include "testing.hpp"
+
+#include <algorithm>
+#include <set>
+
+using namespace datastax::internal::testing;
 
 class ClusterTests : public Integration {
 public:
@@ -95,3 +101,262 @@ CASSANDRA_INTEGRATION_TEST_F(ClusterTests, InvalidProtocolVersions) {
               cass_cluster_set_protocol_version(cluster.get(), CASS_PROTOCOL_VERSION_V4));
   }
 }
+
+/**
+ * Verify the default and the interaction of shuffling replicas with token-aware routing.
+ *
+ * @test_category configuration
+ * @expected_result Replicas are shuffled by default and never without token-aware routing.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, TokenAwareShuffleReplicas) {
+  test::driver::Cluster cluster;
+  EXPECT_TRUE(get_token_aware_shuffle_replicas_from_cluster(cluster.get()));
+
+  cass_cluster_set_token_aware_routing_shuffle_replicas(cluster.get(), cass_false);
+  EXPECT_FALSE(get_token_aware_shuffle_replicas_from_cluster(cluster.get()));
+
+  cass_cluster_set_token_aware_routing_shuffle_replicas(cluster.get(), cass_true);
+  cass_cluster_set_token_aware_routing(cluster.get(), cass_false);
+  EXPECT_FALSE(get_token_aware_shuffle_replicas_from_cluster(cluster.get()));
+
+  cass_cluster_set_token_aware_routing(cluster.get(), cass_true);
+  EXPECT_TRUE(get_token_aware_shuffle_replicas_from_cluster(cluster.get()));
+}
+
+/**
+ * Verify the combinations of connection heartbeat interval and idle timeout.
+ *
+ * @test_category configuration
+ * @expected_result Heartbeats and idle timeout are configured independently and 0 disables them.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, ConnectionHeartbeatAndIdleTimeout) {
+  test::driver::Cluster cluster;
+
+  // Both enabled (defaults)
+  EXPECT_EQ(30u, get_connection_heartbeat_interval_from_cluster(cluster.get()));
+  EXPECT_EQ(60u, get_connection_idle_timeout_from_cluster(cluster.get()));
+
+  // Heartbeats disabled, idle timeout enabled
+  cass_cluster_set_connection_heartbeat_interval(cluster.get(), 0);
+  cass_cluster_set_connection_idle_timeout(cluster.get(), 120);
+  EXPECT_EQ(0u, get_connection_heartbeat_interval_from_cluster(cluster.get()));
+  EXPECT_EQ(120u, get_connection_idle_timeout_from_cluster(cluster.get()));
+
+  // Both disabled
+  cass_cluster_set_connection_idle_timeout(cluster.get(), 0);
+  EXPECT_EQ(0u, get_connection_heartbeat_interval_from_cluster(cluster.get()));
+  EXPECT_EQ(0u, get_connection_idle_timeout_from_cluster(cluster.get()));
+}
+
+/**
+ * Verify that setting the application name is reported as not supported.
+ *
+ * @test_category configuration
+ * @expected_result A warning that the application name is not sent is logged.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, ApplicationNameNotSupported) {
+  logger_.add_critera("Setting application name (\"test application\") is not supported");
+  test::driver::Cluster cluster;
+  cass_cluster_set_application_name(cluster.get(), "test application");
+  EXPECT_EQ(1u, logger_.count());
+}
+
+/**
+ * Verify that the deprecated parameters of dc-aware lbp are accepted with a warning.
+ *
+ * @test_category configuration
+ * @test_category load_balancing_policy:dc_aware
+ * @expected_result CASS_OK is returned and a deprecation warning is logged.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, DcAwareDeprecatedParameters) {
+  logger_.add_critera("used_hosts_per_remote_dc (2) is deprecated and not supported");
+  test::driver::Cluster cluster;
+  EXPECT_EQ(CASS_OK, cass_cluster_set_load_balance_dc_aware(cluster.get(), "dc1", 2, cass_true));
+  EXPECT_EQ(1u, logger_.count());
+}
+
+/**
+ * Verify the error of connecting to unreachable contact points.
+ *
+ * @test_category connection
+ * @expected_result CASS_ERROR_LIB_NO_HOSTS_AVAILABLE is returned with a descriptive message.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, UnreachableContactPoints) {
+  test::driver::Cluster cluster = test::driver::Cluster::build()
+                                      .with_contact_points("127.255.255.254")
+                                      .with_connect_timeout(1000);
+  test::driver::Session session = cluster.connect("", false);
+  EXPECT_EQ(CASS_ERROR_LIB_NO_HOSTS_AVAILABLE, session.connect_error_code());
+  EXPECT_EQ(0u,
+            session.connect_error_message().find("Unable to connect to any contact points"));
+}
+
+/**
+ * Verify that shuffling contact points with a fixed seed is reproducible.
+ *
+ * @test_category configuration
+ * @expected_result The same seed always yields the same permutation of the contact points and
+ *                  the configured order is kept when randomization is disabled.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, RandomizedContactPointsSeed) {
+  const std::string contact_points = "127.0.0.1:9042,127.0.0.2:9042,127.0.0.3:9042";
+  std::vector<std::string> sorted_contact_points = explode(contact_points, ',');
+
+  test::driver::Cluster cluster =
+      test::driver::Cluster::build().with_contact_points("127.0.0.1,127.0.0.2,127.0.0.3");
+  cass_cluster_set_use_randomized_contact_points(cluster.get(), cass_false);
+  EXPECT_EQ(contact_points, get_known_nodes_from_cluster(cluster.get()));
+
+  cass_cluster_set_use_randomized_contact_points(cluster.get(), cass_true);
+  std::set<std::string> permutations;
+  for (uint64_t seed = 0; seed < 10; ++seed) {
+    set_contact_point_shuffle_seed_on_cluster(cluster.get(), seed);
+    std::string known_nodes = get_known_nodes_from_cluster(cluster.get());
+    EXPECT_EQ(known_nodes, get_known_nodes_from_cluster(cluster.get()));
+
+    // Shuffled contact points are a permutation of the configured ones
+    std::vector<std::string> nodes = explode(known_nodes, ',');
+    std::sort(nodes.begin(), nodes.end());
+    EXPECT_EQ(sorted_contact_points, nodes);
+    permutations.insert(known_nodes);
+  }
+  EXPECT_GT(permutations.size(), 1u);
+}
+
+/**
+ * Verify the validation of the local port range and that a valid one is stored.
+ *
+ * @test_category configuration
+ * @expected_result CASS_ERROR_LIB_BAD_PARAMS is returned for invalid ranges and the last valid
+ *                  range is stored.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, LocalPortRange) {
+  test::driver::Cluster cluster;
+  EXPECT_EQ(std::make_pair(0, 0), get_local_port_range_from_cluster(cluster.get()));
+
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS, cass_cluster_set_local_port_range(cluster.get(), 0, 100));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS, cass_cluster_set_local_port_range(cluster.get(), 100, 100));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS, cass_cluster_set_local_port_range(cluster.get(), 200, 100));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_cluster_set_local_port_range(cluster.get(), 100, 65536));
+
+  EXPECT_EQ(CASS_OK, cass_cluster_set_local_port_range(cluster.get(), 49152, 65535));
+  EXPECT_EQ(std::make_pair(49152, 65535), get_local_port_range_from_cluster(cluster.get()));
+
+  // An invalid range does not override the stored one
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS, cass_cluster_set_local_port_range(cluster.get(), -1, 100));
+  EXPECT_EQ(std::make_pair(49152, 65535), get_local_port_range_from_cluster(cluster.get()));
+}
+
+/**
+ * Verify that a heartbeat interval not shorter than the idle timeout is warned about.
+ *
+ * @test_category configuration
+ * @expected_result Both values are kept as configured and a warning is logged when connecting.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, HeartbeatIntervalNotShorterThanIdleTimeout) {
+  logger_.add_critera("is not shorter than connection idle timeout");
+  test::driver::Cluster cluster = test::driver::Cluster::build()
+                                      .with_contact_points("127.255.255.254")
+                                      .with_connect_timeout(1000);
+  cass_cluster_set_connection_heartbeat_interval(cluster.get(), 60);
+  cass_cluster_set_connection_idle_timeout(cluster.get(), 30);
+  EXPECT_EQ(60u, get_connection_heartbeat_interval_from_cluster(cluster.get()));
+  EXPECT_EQ(30u, get_connection_idle_timeout_from_cluster(cluster.get()));
+
+  test::driver::Session session = cluster.connect("", false);
+  EXPECT_EQ(1u, logger_.count());
+
+  // Interval shorter than the idle timeout is not warned about
+  logger_.reset();
+  logger_.add_critera("is not shorter than connection idle timeout");
+  cass_cluster_set_connection_heartbeat_interval(cluster.get(), 10);
+  session = cluster.connect("", false);
+  EXPECT_EQ(0u, logger_.count());
+}
+
+/**
+ * Verify that enabling the NO_COMPACT startup option is reported as not implemented.
+ *
+ * @test_category configuration
+ * @expected_result Enabling returns CASS_ERROR_LIB_NOT_IMPLEMENTED, disabling returns CASS_OK.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, NoCompactNotImplemented) {
+  test::driver::Cluster cluster = test::driver::Cluster::build();
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED, cass_cluster_set_no_compact(cluster.get(), cass_true));
+  EXPECT_EQ(CASS_OK, cass_cluster_set_no_compact(cluster.get(), cass_false));
+}
+
+/**
+ * Verify that CASS_COMPRESSION_NONE disables compression and unknown types are ignored.
+ *
+ * @test_category configuration
+ * @expected_result NONE disables compression, LZ4 enables it and unknown types change nothing.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, Compression) {
+  test::driver::Cluster cluster = test::driver::Cluster::build();
+  EXPECT_EQ(CASS_COMPRESSION_NONE, get_compression_from_cluster(cluster.get()));
+
+  cass_cluster_set_compression(cluster.get(), CASS_COMPRESSION_LZ4);
+  EXPECT_EQ(CASS_COMPRESSION_LZ4, get_compression_from_cluster(cluster.get()));
+
+  cass_cluster_set_compression(cluster.get(), static_cast<CassCompressionType>(42));
+  EXPECT_EQ(CASS_COMPRESSION_LZ4, get_compression_from_cluster(cluster.get()));
+
+  cass_cluster_set_compression(cluster.get(), CASS_COMPRESSION_NONE);
+  EXPECT_EQ(CASS_COMPRESSION_NONE, get_compression_from_cluster(cluster.get()));
+}
+
+/**
+ * Verify that DC failover of the DC-aware policy can be disabled.
+ *
+ * @test_category configuration
+ * @expected_result Failover is permitted by default and is disabled by the setter.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, DcAwarePermitDcFailover) {
+  test::driver::Cluster cluster = test::driver::Cluster::build();
+  EXPECT_TRUE(get_dc_aware_permit_dc_failover_from_cluster(cluster.get()));
+
+  EXPECT_EQ(CASS_OK, cass_cluster_set_load_balance_dc_aware(cluster.get(), "dc1", 0, cass_true));
+  EXPECT_TRUE(get_dc_aware_permit_dc_failover_from_cluster(cluster.get()));
+  EXPECT_EQ(CASS_OK,
+            cass_cluster_set_load_balance_dc_aware_permit_dc_failover(cluster.get(), cass_false));
+  EXPECT_FALSE(get_dc_aware_permit_dc_failover_from_cluster(cluster.get()));
+
+  // The setter requires the DC-aware policy
+  cass_cluster_set_load_balance_round_robin(cluster.get());
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS,
+            cass_cluster_set_load_balance_dc_aware_permit_dc_failover(cluster.get(), cass_true));
+}
+
+/**
+ * Verify that enabling DSE GSSAPI authentication is reported as not implemented.
+ *
+ * @test_category configuration
+ * @expected_result CASS_ERROR_LIB_NOT_IMPLEMENTED is returned by both setters.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, DseGssapiAuthenticatorNotImplemented) {
+  test::driver::Cluster cluster = test::driver::Cluster::build();
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_cluster_set_dse_gssapi_authenticator(cluster.get(), "dse", "user"));
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_cluster_set_dse_gssapi_authenticator_n(cluster.get(), "dse", 3, "user", 4));
+}
+
+/**
+ * Verify that the coalesce delay is validated and stored.
+ *
+ * @test_category configuration
+ * @expected_result Negative delays are rejected and leave the stored delay unchanged.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ClusterTests, CoalesceDelay) {
+  test::driver::Cluster cluster = test::driver::Cluster::build();
+  EXPECT_EQ(-1, get_coalesce_delay_from_cluster(cluster.get()));
+
+  EXPECT_EQ(CASS_OK, cass_cluster_set_coalesce_delay(cluster.get(), 200));
+  EXPECT_EQ(200, get_coalesce_delay_from_cluster(cluster.get()));
+  EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS, cass_cluster_set_coalesce_delay(cluster.get(), -1));
+  EXPECT_EQ(200, get_coalesce_delay_from_cluster(cluster.get()));
+  EXPECT_EQ(CASS_OK, cass_cluster_set_coalesce_delay(cluster.get(), 0));
+  EXPECT_EQ(0, get_coalesce_delay_from_cluster(cluster.get()));
+}
@@ -74,3 +74,43 @@ CASSANDRA_INTEGRATION_TEST_F(CustomPayloadTests, Simple) {
     ASSERT_EQ(expected_item.second, item.second);
   }
 }
+
+/**
+ * Custom payload integration tests which do not require the mirroring query handler
+ */
+class CustomPayloadItemsTests : public Integration {};
+
+/**
+ * Perform an execution of a statement with a custom payload of multiple items
+ *
+ * This test will ensure that a statement with three items is executed, and
+ * that the items of the response are reported as not implemented rather than
+ * as successfully read.
+ *
+ * @test_category queries:custom_payload
+ * @expected_result Statement is executed and no response items are returned
+ */
+CASSANDRA_INTEGRATION_TEST_F(CustomPayloadItemsTests, MultipleItems) {
+  CHECK_FAILURE;
+
+  CustomPayload custom_payload;
+  custom_payload.set("key1", Blob("value1"));
+  custom_payload.set("key2", Blob("value2"));
+  custom_payload.set("key3", Blob("value3"));
+
+  Statement statement(SELECT_ALL_SYSTEM_LOCAL_CQL);
+  statement.set_custom_payload(custom_payload);
+  Future future = session_.execute_async(statement);
+  ASSERT_EQ(CASS_OK, future.error_code());
+
+  EXPECT_EQ(0u, cass_future_custom_payload_item_count(future.get()));
+  const char* name = NULL;
+  size_t name_length = 0;
+  const cass_byte_t* value = NULL;
+  size_t value_size = 0;
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_future_custom_payload_item(future.get(), 0, &name, &name_length, &value,
+                                            &value_size));
+  EXPECT_TRUE(name == NULL);
+  EXPECT_TRUE(value == NULL);
+}
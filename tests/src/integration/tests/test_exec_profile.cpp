@@ -814,3 +814,233 @@ CASSANDRA_INTEGRATION_TEST_F(DCExecutionProfileTest, WhitelistDCFiltering) {
     ASSERT_STREQ(expected_ip_address.c_str(), result.host().c_str());
   }
 }
+
+/**
+ * Execution profile integration tests for settings which cannot be applied per request
+ */
+class ExecutionProfileNotImplementedTests : public Integration {
+public:
+  ExecutionProfileNotImplementedTests() { is_ccm_requested_ = false; }
+};
+
+/**
+ * Verify that speculative execution policies cannot be set for a profile.
+ *
+ * @test_category execution_profiles
+ * @test_category configuration
+ * @expected_result CASS_ERROR_LIB_NOT_IMPLEMENTED is returned by both setters.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfileNotImplementedTests, SpeculativeExecutionPolicy) {
+  ExecutionProfile profile;
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_constant_speculative_execution_policy(profile.get(), 100,
+                                                                             2));
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_no_speculative_execution_policy(profile.get()));
+}
+
+/**
+ * Verify that load balancing settings cannot be set for a profile, while its
+ * request timeout can.
+ *
+ * @test_category execution_profiles
+ * @test_category load_balancing_policy
+ * @expected_result CASS_ERROR_LIB_NOT_IMPLEMENTED is returned by the load balancing setters.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfileNotImplementedTests, LoadBalancing) {
+  ExecutionProfile profile;
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_load_balance_round_robin(profile.get()));
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_load_balance_dc_aware(profile.get(), "dc1", 0, cass_false));
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_token_aware_routing(profile.get(), cass_false));
+  EXPECT_EQ(CASS_OK, cass_execution_profile_set_request_timeout(profile.get(), 1000));
+}
+
+/**
+ * Verify that latency-aware routing cannot be set for a profile.
+ *
+ * @test_category execution_profiles
+ * @test_category load_balancing_policy:latency_aware
+ * @expected_result CASS_ERROR_LIB_NOT_IMPLEMENTED is returned by both setters.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfileNotImplementedTests, LatencyAwareRouting) {
+  ExecutionProfile profile;
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_latency_aware_routing(profile.get(), cass_true));
+  EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+            cass_execution_profile_set_latency_aware_routing_settings(profile.get(), 2.0, 100,
+                                                                      10000, 100, 50));
+}
+
+/**
+ * Execution profile integration tests for paging
+ */
+class ExecutionProfilePagingTests : public Integration {
+public:
+  void SetUp() {
+    profiles_["consistency"] = ExecutionProfile::build().with_consistency(CASS_CONSISTENCY_ONE);
+    Integration::SetUp();
+
+    session_.execute(
+        format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+    for (int i = 0; i < 10; ++i) {
+      std::string value = format_string("%d", i);
+      session_.execute(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(),
+                                     value.c_str(), value.c_str()));
+    }
+  }
+};
+
+/**
+ * Verify that the page size of a statement is used regardless of its execution profile.
+ *
+ * Page size is not a part of execution profiles, so a statement using a profile
+ * is not paged unless the page size is set for the statement itself.
+ *
+ * @test_category execution_profiles
+ * @test_category queries:paging
+ * @expected_result The statement's page size is used, otherwise all rows are returned.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfilePagingTests, PagingSize) {
+  CHECK_FAILURE;
+
+  Statement statement(default_select_all());
+  statement.set_execution_profile("consistency");
+  Result result = session_.execute(statement);
+  EXPECT_EQ(10u, result.row_count());
+  EXPECT_FALSE(result.has_more_pages());
+
+  statement.set_paging_size(3);
+  result = session_.execute(statement);
+  EXPECT_EQ(3u, result.row_count());
+  EXPECT_TRUE(result.has_more_pages());
+}
+
+/**
+ * Execution profile integration tests for speculative execution
+ */
+class ExecutionProfileSpeculativeExecutionTests : public Integration {
+public:
+  void SetUp() {
+    ExecutionProfile profile = ExecutionProfile::build().with_consistency(CASS_CONSISTENCY_ONE);
+    EXPECT_EQ(CASS_ERROR_LIB_NOT_IMPLEMENTED,
+              cass_execution_profile_set_constant_speculative_execution_policy(profile.get(), 10,
+                                                                               2));
+    profiles_["speculative"] = profile;
+    Integration::SetUp();
+  }
+
+  Cluster default_cluster(bool is_with_default_contact_points = true) {
+    return Integration::default_cluster(is_with_default_contact_points)
+        .with_constant_speculative_execution_policy(10, 2);
+  }
+};
+
+/**
+ * Verify that an idempotent statement using a profile is executed with the
+ * speculative execution policy of the cluster.
+ *
+ * Speculative execution policies cannot be set per profile, so the profile's
+ * setter fails and the statement uses the policy configured for the cluster.
+ *
+ * @test_category execution_profiles
+ * @test_category configuration
+ * @expected_result The statement is executed successfully.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfileSpeculativeExecutionTests, ClusterPolicyIsUsed) {
+  CHECK_FAILURE;
+
+  Statement statement("SELECT release_version FROM system.local");
+  statement.set_idempotent(true);
+  statement.set_execution_profile("speculative");
+  Result result = session_.execute(statement);
+  EXPECT_EQ(CASS_OK, result.error_code());
+  EXPECT_EQ(1u, result.row_count());
+}
+
+/**
+ * Execution profile integration tests for eager validation of profile names
+ */
+class ExecutionProfileCheckedTests : public Integration {
+public:
+  void SetUp() {
+    profiles_["registered"] = ExecutionProfile::build().with_consistency(CASS_CONSISTENCY_ONE);
+    Integration::SetUp();
+  }
+};
+
+/**
+ * Verify that an eagerly validated profile name is rejected unless it is
+ * registered in the connected session.
+ *
+ * @test_category execution_profiles
+ * @expected_result CASS_ERROR_LIB_EXECUTION_PROFILE_INVALID is returned for an unknown profile
+ *                  and CASS_ERROR_LIB_NO_HOSTS_AVAILABLE for a session which is not connected.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfileCheckedTests, UnknownProfile) {
+  CHECK_FAILURE;
+
+  Statement statement("SELECT release_version FROM system.local");
+  EXPECT_EQ(CASS_ERROR_LIB_EXECUTION_PROFILE_INVALID,
+            cass_statement_set_execution_profile_checked(statement.get(), session_.get(),
+                                                         "unknown"));
+  EXPECT_EQ(CASS_OK, cass_statement_set_execution_profile_checked(statement.get(),
+                                                                  session_.get(), "registered"));
+  EXPECT_EQ(CASS_OK, session_.execute(statement).error_code());
+
+  // Clearing the profile needs no validation
+  EXPECT_EQ(CASS_OK,
+            cass_statement_set_execution_profile_checked(statement.get(), session_.get(), NULL));
+
+  Session not_connected;
+  EXPECT_EQ(CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+            cass_statement_set_execution_profile_checked(statement.get(), not_connected.get(),
+                                                         "registered"));
+}
+
+/**
+ * Execution profile integration tests for consistency precedence
+ */
+class ExecutionProfileConsistencyTests : public Integration {
+public:
+  void SetUp() {
+    profiles_["serial"] = ExecutionProfile::build().with_consistency(CASS_CONSISTENCY_SERIAL);
+    Integration::SetUp();
+
+    session_.execute(
+        format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  }
+};
+
+/**
+ * Verify that the consistency of a statement takes precedence over the one of its profile.
+ *
+ * The profile's SERIAL consistency is invalid for writes, so the insert fails
+ * unless the consistency set for the statement is used, regardless of whether
+ * it was set before or after the profile.
+ *
+ * @test_category execution_profiles
+ * @test_category consistency
+ * @expected_result The insert fails with the profile's consistency only.
+ */
+CASSANDRA_INTEGRATION_TEST_F(ExecutionProfileConsistencyTests, StatementOverridesProfile) {
+  CHECK_FAILURE;
+
+  std::string insert =
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "1", "1");
+  Statement statement(insert);
+  statement.set_execution_profile("serial");
+  EXPECT_EQ(CASS_ERROR_SERVER_INVALID_QUERY, session_.execute(statement, false).error_code());
+
+  // Consistency set after the profile
+  statement.set_consistency(CASS_CONSISTENCY_ONE);
+  EXPECT_EQ(CASS_OK, session_.execute(statement).error_code());
+
+  // Consistency set before the profile
+  Statement other(insert);
+  other.set_consistency(CASS_CONSISTENCY_ONE);
+  other.set_execution_profile("serial");
+  EXPECT_EQ(CASS_OK, session_.execute(other).error_code());
+}
@@ -184,3 +184,26 @@ CASSANDRA_INTEGRATION_TEST_F(MetricsTests, SpeculativeExecutionRequests) {
   EXPECT_GT(metrics.percentage, 0.0);
   EXPECT_GT(metrics.count, 0u);
 }
+
+/**
+ * This test ensures that the latency percentiles of requests are populated in microseconds.
+ */
+CASSANDRA_INTEGRATION_TEST_F(MetricsTests, RequestLatencyPercentiles) {
+  CHECK_FAILURE;
+
+  for (int i = 0; i < 100; ++i) {
+    session_.execute(SELECT_ALL_SYSTEM_LOCAL_CQL);
+  }
+
+  CassMetrics metrics = session_.metrics();
+  EXPECT_GT(metrics.requests.min, 0u);
+  EXPECT_GT(metrics.requests.median, 0u);
+  EXPECT_GE(metrics.requests.percentile_75th, metrics.requests.median);
+  EXPECT_GE(metrics.requests.percentile_95th, metrics.requests.percentile_75th);
+  EXPECT_GE(metrics.requests.percentile_99th, metrics.requests.median);
+  EXPECT_GE(metrics.requests.max, metrics.requests.percentile_99th);
+  EXPECT_LE(metrics.requests.min, metrics.requests.mean);
+  EXPECT_GE(metrics.requests.max, metrics.requests.mean);
+  // Latencies are not rounded to whole milliseconds
+  EXPECT_NE(0u, metrics.requests.mean % 1000);
+}
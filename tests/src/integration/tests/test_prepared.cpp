@@ -15,6 +15,9 @@
 */
 
 #include "integration.hpp"
+#include "testing.hpp"
+
+using namespace datastax::internal::testing;
 
 /**
  * Prepared integration tests; common operations
@@ -195,3 +198,231 @@ CASSANDRA_INTEGRATION_TEST_F(PreparedTests, PrepareFromExistingBoundStatement) {
   ASSERT_EQ(result.row_count(), 1u);
   EXPECT_EQ(result.first_row().column_by_name<Integer>("value").value(), 99);
 }
+
+/**
+ * Verify that integers bound to narrower columns of a prepared statement are
+ * rejected client-side when they are out of the column's range.
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, BindOutOfRangeInt32ToTinyInt) {
+  CHECK_FAILURE;
+
+  use_keyspace(keyspace_name_);
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "tinyint"));
+  Prepared insert_prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+
+  Statement insert_statement = insert_prepared.bind();
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_int32(insert_statement.get(), 1, 128));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_int32(insert_statement.get(), 1, -129));
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_int64(insert_statement.get(), 1, 1000));
+
+  insert_statement.bind<Integer>(0, Integer(1));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_int32(insert_statement.get(), 1, -128));
+  session_.execute(insert_statement);
+
+  Result result = session_.execute(
+      format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "1"));
+  ASSERT_EQ(result.row_count(), 1u);
+  EXPECT_EQ(result.first_row().column_by_name<TinyInteger>("value").value(), -128);
+}
+
+/**
+ * Verify that an int32 bound to a tinyint column of a prepared statement is
+ * narrowed to the column's type, and values out of its range are rejected.
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, BindInt32ToTinyInt) {
+  CHECK_FAILURE;
+
+  use_keyspace(keyspace_name_);
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "tinyint"));
+  Prepared insert_prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+
+  Statement insert_statement = insert_prepared.bind();
+  insert_statement.bind<Integer>(0, Integer(1));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_int32(insert_statement.get(), 1, 100));
+  session_.execute(insert_statement);
+
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_int32(insert_statement.get(), 1, 100000));
+
+  Result result = session_.execute(
+      format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "1"));
+  ASSERT_EQ(result.row_count(), 1u);
+  EXPECT_EQ(result.first_row().column_by_name<TinyInteger>("value").value(), 100);
+}
+
+/**
+ * Verify that a UUID bound to a prepared statement is accepted by uuid and
+ * timeuuid columns, and that only time-based UUIDs are accepted by the latter.
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, BindUuidToUuidAndTimeUuid) {
+  CHECK_FAILURE;
+
+  use_keyspace(keyspace_name_);
+
+  CassUuid random_uuid;
+  CassUuid time_uuid;
+  ASSERT_EQ(CASS_OK, cass_uuid_from_string("550e8400-e29b-41d4-a716-446655440000", &random_uuid));
+  ASSERT_EQ(CASS_OK, cass_uuid_from_string("fe2b4360-28c6-11e2-81c1-0800200c9a66", &time_uuid));
+
+  { // uuid column accepts any UUID
+    std::string table_name = table_name_ + "_uuid";
+    session_.execute(
+        format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name.c_str(), "int", "uuid"));
+    Statement statement = session_.prepare(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT,
+                                                         table_name.c_str(), "?", "?"))
+                              .bind();
+    statement.bind<Integer>(0, Integer(1));
+    EXPECT_EQ(CASS_OK, cass_statement_bind_uuid(statement.get(), 1, random_uuid));
+    session_.execute(statement);
+
+    Result result =
+        session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name.c_str(), "1"));
+    ASSERT_EQ(1u, result.row_count());
+    CassUuid value;
+    ASSERT_EQ(CASS_OK, cass_value_get_uuid(result.first_row().next().get_value(), &value));
+    EXPECT_EQ(random_uuid.time_and_version, value.time_and_version);
+    EXPECT_EQ(random_uuid.clock_seq_and_node, value.clock_seq_and_node);
+  }
+
+  { // timeuuid column accepts only time-based UUIDs
+    std::string table_name = table_name_ + "_timeuuid";
+    session_.execute(
+        format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name.c_str(), "int", "timeuuid"));
+    Statement statement = session_.prepare(format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT,
+                                                         table_name.c_str(), "?", "?"))
+                              .bind();
+    statement.bind<Integer>(0, Integer(1));
+    EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+              cass_statement_bind_uuid(statement.get(), 1, random_uuid));
+    EXPECT_EQ(CASS_OK, cass_statement_bind_uuid(statement.get(), 1, time_uuid));
+    session_.execute(statement);
+
+    Result result =
+        session_.execute(format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name.c_str(), "1"));
+    ASSERT_EQ(1u, result.row_count());
+    CassUuid value;
+    ASSERT_EQ(CASS_OK, cass_value_get_uuid(result.first_row().next().get_value(), &value));
+    EXPECT_EQ(time_uuid.time_and_version, value.time_and_version);
+    EXPECT_EQ(time_uuid.clock_seq_and_node, value.clock_seq_and_node);
+  }
+}
+
+/**
+ * Free a prepared statement twice
+ *
+ * This test will free the same prepared statement twice and ensure that the
+ * double free is detected and the process is aborted instead of corrupting
+ * the reference count of the prepared statement.
+ *
+ * @test_category queries:prepared
+ * @expected_result Double free is detected in debug builds
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, DoubleFreeDetected) {
+  CHECK_FAILURE;
+  if (!get_debug_assertions_enabled()) {
+    SKIP_TEST("Double free detection is only enabled in debug builds");
+  }
+
+  CassFuture* future = cass_session_prepare(session_.get(), SELECT_ALL_SYSTEM_LOCAL_CQL);
+  ASSERT_EQ(CASS_OK, cass_future_error_code(future));
+  const CassPrepared* prepared = cass_future_get_prepared(future);
+  cass_future_free(future);
+  ASSERT_TRUE(prepared != NULL);
+
+  cass_prepared_free(prepared);
+  EXPECT_DEATH(cass_prepared_free(prepared), "Double free of CassPrepared detected");
+}
+
+/**
+ * Execute a prepared statement which is unknown to the server
+ *
+ * This test will prepare a statement, then drop and re-create the table to
+ * force the server to invalidate the prepared statement, so the server
+ * responds to its execution with an UNPREPARED error. The statement should
+ * be re-prepared and the execution retried transparently.
+ *
+ * @test_category queries:prepared
+ * @expected_result The insert succeeds without the UNPREPARED error being returned.
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, ReprepareOnUnprepared) {
+  CHECK_FAILURE;
+
+  // Create the table and initial prepared statement
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_QUALIFIED_TABLE_FORMAT, keyspace_name_.c_str(),
+                                 table_name_.c_str(), "int", "int"));
+  Prepared insert_prepared =
+      session_.prepare(format_string(CASSANDRA_KEY_VALUE_QUALIFIED_INSERT_FORMAT,
+                                     keyspace_name_.c_str(), table_name_.c_str(), "?", "?"));
+
+  // Drop and re-create the table to invalidate the prepared statement on the server
+  session_.execute(
+      format_string("DROP TABLE %s.%s", keyspace_name_.c_str(), table_name_.c_str()));
+  session_.execute(format_string(CASSANDRA_KEY_VALUE_QUALIFIED_TABLE_FORMAT, keyspace_name_.c_str(),
+                                 table_name_.c_str(), "int", "int"));
+
+  // Execute the insert statement and validate success
+  Statement insert_statement = insert_prepared.bind();
+  insert_statement.bind<Integer>(0, Integer(0));
+  insert_statement.bind<Integer>(1, Integer(1));
+  Result result = session_.execute(insert_statement, false);
+  EXPECT_EQ(CASS_OK, result.error_code()) << result.error_message();
+}
+
+/**
+ * Prepare a statement on a node which does not respond
+ *
+ * This test will pause the only node and ensure that preparing a statement is
+ * bounded by the request timeout of the cluster.
+ *
+ * @test_category prepared_statements
+ * @expected_result CASS_ERROR_LIB_REQUEST_TIMED_OUT is returned by the prepare future
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, PrepareTimeout) {
+  CHECK_FAILURE;
+  is_test_chaotic_ = true; // Destroy the cluster after the test completes
+
+  Session session = default_cluster().with_request_timeout(1000).connect();
+
+  pause_node(1);
+  Future future(cass_session_prepare(session.get(), SELECT_ALL_SYSTEM_LOCAL_CQL));
+  EXPECT_EQ(CASS_ERROR_LIB_REQUEST_TIMED_OUT, future.error_code());
+  resume_node(1);
+}
+
+/**
+ * Verify that int64 values bound to an int column of a prepared statement are
+ * narrowed to the column's type when they are in its range, and rejected
+ * client-side otherwise.
+ */
+CASSANDRA_INTEGRATION_TEST_F(PreparedTests, BindInt64ToInt) {
+  CHECK_FAILURE;
+
+  use_keyspace(keyspace_name_);
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  Prepared insert_prepared = session_.prepare(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "?", "?"));
+
+  Statement insert_statement = insert_prepared.bind();
+  EXPECT_EQ(CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            cass_statement_bind_int64(insert_statement.get(), 1, 2147483648LL));
+
+  insert_statement.bind<Integer>(0, Integer(1));
+  EXPECT_EQ(CASS_OK, cass_statement_bind_int64(insert_statement.get(), 1, -2147483648LL));
+  session_.execute(insert_statement);
+
+  Result result = session_.execute(
+      format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "1"));
+  ASSERT_EQ(result.row_count(), 1u);
+  EXPECT_EQ(result.first_row().column_by_name<Integer>("value").value(), -2147483648LL);
+}
@@ -84,6 +84,25 @@ public:
 
 protected:
   Schema schema_meta_;
+
+  // Raw fields of tables are refreshed in the background after the schema changes,
+  // so wait until the fields of the table created by populateSchema() are available.
+  const CassSchemaMeta* schema_meta_with_table_fields() {
+    for (int i = 0; i < 100; ++i) {
+      const CassSchemaMeta* schema_meta = session_.schema_meta();
+      const CassKeyspaceMeta* keyspace_meta =
+          cass_schema_meta_keyspace_by_name(schema_meta, keyspace_name_.c_str());
+      const CassTableMeta* table_meta =
+          keyspace_meta ? cass_keyspace_meta_table_by_name(keyspace_meta, table_name_.c_str())
+                        : NULL;
+      if (table_meta && cass_table_meta_field_by_name(table_meta, "gc_grace_seconds")) {
+        return schema_meta;
+      }
+      cass_schema_meta_free(schema_meta);
+      msleep(100);
+    }
+    return session_.schema_meta();
+  }
 };
 
 CASSANDRA_INTEGRATION_TEST_F(SchemaMetadataTest, Views) {
@@ -354,3 +373,83 @@ CASSANDRA_INTEGRATION_TEST_F(SchemaMetadataTest, MetadataIterator) {
 
   cass_schema_meta_free(schema_meta);
 }
+
+CASSANDRA_INTEGRATION_TEST_F(SchemaMetadataTest, TableMetaFields) {
+  const CassSchemaMeta* schema_meta = schema_meta_with_table_fields();
+
+  const CassKeyspaceMeta* keyspace_meta = cass_schema_meta_keyspace_by_name(schema_meta, keyspace_name_.c_str());
+  ASSERT_TRUE(keyspace_meta);
+  const CassTableMeta* table_meta = cass_keyspace_meta_table_by_name(keyspace_meta, table_name_.c_str());
+  ASSERT_TRUE(table_meta);
+
+  const CassValue* field_value = cass_table_meta_field_by_name(table_meta, "table_name");
+  ASSERT_TRUE(field_value);
+  const char* table_name;
+  size_t table_name_length;
+  ASSERT_EQ(cass_value_get_string(field_value, &table_name, &table_name_length), CASS_OK);
+  EXPECT_EQ(std::string(table_name, table_name_length), table_name_);
+
+  // The table is created with the default gc_grace_seconds.
+  const CassValue* gc_grace_seconds = cass_table_meta_field_by_name(table_meta, "gc_grace_seconds");
+  ASSERT_TRUE(gc_grace_seconds);
+  EXPECT_EQ(cass_value_type(gc_grace_seconds), CASS_VALUE_TYPE_INT);
+  cass_int32_t gc_grace_seconds_value;
+  ASSERT_EQ(cass_value_get_int32(gc_grace_seconds, &gc_grace_seconds_value), CASS_OK);
+  EXPECT_EQ(gc_grace_seconds_value, 864000);
+
+  const CassValue* compaction = cass_table_meta_field_by_name(table_meta, "compaction");
+  ASSERT_TRUE(compaction);
+  EXPECT_EQ(cass_value_type(compaction), CASS_VALUE_TYPE_MAP);
+
+  EXPECT_FALSE(cass_table_meta_field_by_name(table_meta, "invalid"));
+
+  cass_schema_meta_free(schema_meta);
+}
+
+CASSANDRA_INTEGRATION_TEST_F(SchemaMetadataTest, IteratorMetaFields) {
+  const CassSchemaMeta* schema_meta = schema_meta_with_table_fields();
+
+  const CassKeyspaceMeta* keyspace_meta = cass_schema_meta_keyspace_by_name(schema_meta, keyspace_name_.c_str());
+  ASSERT_TRUE(keyspace_meta);
+  const CassTableMeta* table_meta = cass_keyspace_meta_table_by_name(keyspace_meta, table_name_.c_str());
+  ASSERT_TRUE(table_meta);
+
+  // Keyspace fields are the columns of `system_schema.keyspaces`.
+  const CassValue* durable_writes = NULL;
+  CassIterator* keyspace_fields_iterator = cass_iterator_fields_from_keyspace_meta(keyspace_meta);
+  while (cass_iterator_next(keyspace_fields_iterator)) {
+    const char* name;
+    size_t name_length;
+    ASSERT_EQ(cass_iterator_get_meta_field_name(keyspace_fields_iterator, &name, &name_length),
+              CASS_OK);
+    if (std::string(name, name_length) == "durable_writes") {
+      durable_writes = cass_iterator_get_meta_field_value(keyspace_fields_iterator);
+    }
+  }
+  ASSERT_TRUE(durable_writes);
+  cass_bool_t durable_writes_value;
+  ASSERT_EQ(cass_value_get_bool(durable_writes, &durable_writes_value), CASS_OK);
+  EXPECT_EQ(durable_writes_value, cass_true);
+  cass_iterator_free(keyspace_fields_iterator);
+
+  // Table fields are the columns of `system_schema.tables`.
+  const CassValue* bloom_filter_fp_chance = NULL;
+  CassIterator* table_fields_iterator = cass_iterator_fields_from_table_meta(table_meta);
+  while (cass_iterator_next(table_fields_iterator)) {
+    const char* name;
+    size_t name_length;
+    ASSERT_EQ(cass_iterator_get_meta_field_name(table_fields_iterator, &name, &name_length),
+              CASS_OK);
+    if (std::string(name, name_length) == "bloom_filter_fp_chance") {
+      bloom_filter_fp_chance = cass_iterator_get_meta_field_value(table_fields_iterator);
+    }
+  }
+  ASSERT_TRUE(bloom_filter_fp_chance);
+  EXPECT_EQ(cass_value_type(bloom_filter_fp_chance), CASS_VALUE_TYPE_DOUBLE);
+  cass_double_t bloom_filter_fp_chance_value;
+  ASSERT_EQ(cass_value_get_double(bloom_filter_fp_chance, &bloom_filter_fp_chance_value), CASS_OK);
+  EXPECT_GT(bloom_filter_fp_chance_value, 0.0);
+  cass_iterator_free(table_fields_iterator);
+
+  cass_schema_meta_free(schema_meta);
+}
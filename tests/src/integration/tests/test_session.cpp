@@ -160,3 +160,125 @@ CASSANDRA_INTEGRATION_TEST_F(SessionTest, ExternalHostListener) {
 
   session.close();
 }
+
+/**
+ * Session integration tests for the host listener with a two node cluster
+ */
+class SessionHostListenerTests : public SessionTest {
+public:
+  SessionHostListenerTests() { number_dc1_nodes_ = 2; }
+};
+
+/**
+ * Verify that stopping a node is reported to the host listener as a DOWN event.
+ *
+ * Nodes are polled, so nodes known when the session connects are not reported
+ * and only the changes which happen afterwards are.
+ *
+ * @test_category control_connection
+ * @expected_result A single DOWN event is reported for the stopped node.
+ */
+CASSANDRA_INTEGRATION_TEST_F(SessionHostListenerTests, NodeDown) {
+  CHECK_FAILURE;
+  is_test_chaotic_ = true; // Destroy the cluster after the test completes
+
+  Cluster cluster = default_cluster().with_load_balance_round_robin().with_host_listener_callback(
+      on_host_listener, this);
+  Session session = cluster.connect();
+
+  // Nodes known when the session is connected are not reported
+  EXPECT_FALSE(wait_for_event(1u));
+
+  stop_node(2);
+  ASSERT_TRUE(wait_for_event(1u));
+  check_event(CASS_HOST_LISTENER_EVENT_DOWN, 2);
+  EXPECT_FALSE(wait_for_event(1u));
+
+  session.close();
+}
+
+/**
+ * Verify that a threshold of concurrent requests equal to 1 serializes requests.
+ *
+ * Both requests time out on a paused node, so the second one, which waits for
+ * the first one to complete before it is sent, completes after two timeouts.
+ * Preparing counts as a request as well.
+ *
+ * @test_category configuration
+ * @expected_result The second request completes after the sum of both timeouts.
+ */
+CASSANDRA_INTEGRATION_TEST_F(SessionTest, MaxConcurrentRequestsThreshold) {
+  CHECK_FAILURE;
+  is_test_chaotic_ = true; // Destroy the cluster after the test completes
+
+  Cluster cluster = default_cluster().with_request_timeout(1000);
+  EXPECT_EQ(CASS_OK, cass_cluster_set_max_concurrent_requests_threshold(cluster.get(), 1));
+  Session session = cluster.connect();
+  Statement statement(SELECT_ALL_SYSTEM_LOCAL_CQL);
+
+  pause_node(1);
+  start_timer();
+  Future first(cass_session_execute(session.get(), statement.get()));
+  Future second(cass_session_prepare(session.get(), SELECT_ALL_SYSTEM_LOCAL_CQL));
+  EXPECT_EQ(CASS_ERROR_LIB_REQUEST_TIMED_OUT, first.error_code());
+  EXPECT_EQ(CASS_ERROR_LIB_REQUEST_TIMED_OUT, second.error_code());
+  EXPECT_GE(elapsed_time(), 2000u);
+  resume_node(1);
+}
+
+/**
+ * Verify that the session connect deadline bounds the whole connect.
+ *
+ * Connections to a paused node are established, but the node does not respond
+ * to the handshake, so only the deadline of the whole connect can end it before
+ * the (longer) connect timeout.
+ *
+ * @test_category configuration
+ * @expected_result The connect fails with CASS_ERROR_LIB_REQUEST_TIMED_OUT after the deadline.
+ */
+CASSANDRA_INTEGRATION_TEST_F(SessionTest, ConnectDeadline) {
+  CHECK_FAILURE;
+  is_test_chaotic_ = true; // Destroy the cluster after the test completes
+
+  Cluster cluster = default_cluster().with_connect_timeout(30000);
+  cass_cluster_set_session_connect_deadline(cluster.get(), 1000);
+
+  pause_node(1);
+  start_timer();
+  Session session = cluster.connect("", false);
+  EXPECT_EQ(CASS_ERROR_LIB_REQUEST_TIMED_OUT, session.connect_error_code());
+  EXPECT_GE(elapsed_time(), 1000u);
+  EXPECT_LT(elapsed_time(), 10000u);
+  resume_node(1);
+
+  // Without pausing, the session connects before the deadline
+  session = cluster.connect();
+}
+
+/**
+ * Verify that a cluster request timeout of 0 disables the timeout.
+ *
+ * The statement's own timeout still applies, while a request without any
+ * timeout waits for the paused node to respond.
+ *
+ * @test_category configuration
+ * @expected_result The statement with a timeout times out; the other one succeeds after resuming.
+ */
+CASSANDRA_INTEGRATION_TEST_F(SessionTest, DisabledClusterRequestTimeout) {
+  CHECK_FAILURE;
+  is_test_chaotic_ = true; // Destroy the cluster after the test completes
+
+  Session session = default_cluster().with_request_timeout(0).connect();
+  Statement timed(SELECT_ALL_SYSTEM_LOCAL_CQL);
+  timed.set_request_timeout(1000);
+  Statement untimed(SELECT_ALL_SYSTEM_LOCAL_CQL);
+
+  pause_node(1);
+  Future timed_future(cass_session_execute(session.get(), timed.get()));
+  EXPECT_EQ(CASS_ERROR_LIB_REQUEST_TIMED_OUT, timed_future.error_code());
+
+  Future untimed_future(cass_session_execute(session.get(), untimed.get()));
+  EXPECT_EQ(cass_false, cass_future_wait_timed(untimed_future.get(), 3000000));
+  resume_node(1);
+  EXPECT_EQ(CASS_OK, untimed_future.error_code());
+}
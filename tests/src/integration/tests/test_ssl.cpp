@@ -106,6 +106,48 @@ CASSANDRA_INTEGRATION_TEST_F(SslNoClusterTests, InvalidPeerCert) {
   }
 }
 
+/**
+ * Ensures trusted certificates are loaded from a directory and invalid ones return an error.
+ */
+CASSANDRA_INTEGRATION_TEST_F(SslNoClusterTests, TrustedCertDir) {
+  std::string certs_path = Utils::temp_directory() + Utils::PATH_SEPARATOR + "trusted_certs";
+  std::string invalid_certs_path =
+      Utils::temp_directory() + Utils::PATH_SEPARATOR + "invalid_trusted_certs";
+  Utils::mkdir(certs_path);
+  Utils::mkdir(invalid_certs_path);
+  {
+    std::ofstream out((certs_path + Utils::PATH_SEPARATOR + "cassandra.pem").c_str(),
+                      std::ios::trunc);
+    out << SslCertificates::cassandra_pem();
+  }
+  {
+    std::ofstream out((certs_path + Utils::PATH_SEPARATOR + "driver.pem").c_str(),
+                      std::ios::trunc);
+    out << SslCertificates::driver_pem();
+  }
+  {
+    std::ofstream out((invalid_certs_path + Utils::PATH_SEPARATOR + "invalid.pem").c_str(),
+                      std::ios::trunc);
+    out << "invalid";
+  }
+
+  { // Valid
+    Ssl ssl;
+    EXPECT_EQ(CASS_OK, cass_ssl_add_trusted_cert_dir(ssl.get(), certs_path.c_str()));
+  }
+
+  { // Null
+    Ssl ssl;
+    EXPECT_EQ(CASS_ERROR_LIB_BAD_PARAMS, cass_ssl_add_trusted_cert_dir(ssl.get(), NULL));
+  }
+
+  { // Invalid
+    Ssl ssl;
+    EXPECT_EQ(CASS_ERROR_SSL_INVALID_CERT,
+              cass_ssl_add_trusted_cert_dir(ssl.get(), invalid_certs_path.c_str()));
+  }
+}
+
 /**
  * Ensures NULL and invalid private key values return an error when using the C API.
  */
@@ -170,6 +212,33 @@ CASSANDRA_INTEGRATION_TEST_F(SslTests, VerifyPeerIdentity) {
   write_and_read();
 }
 
+/**
+ * Ensures that two sessions connected from one SSL-configured cluster work independently, after
+ * the SSL context is freed and after one of the sessions is closed.
+ */
+CASSANDRA_INTEGRATION_TEST_F(SslTests, TwoSessionsFromOneCluster) {
+  CHECK_FAILURE;
+
+  Cluster cluster = default_cluster();
+  {
+    // The cluster holds its own reference to the SSL context
+    Ssl ssl;
+    ssl.with_verify_flags(CASS_SSL_VERIFY_PEER_CERT);
+    ssl.add_trusted_cert(SslCertificates::cassandra_pem());
+    cluster.with_ssl(ssl);
+  }
+
+  Session other = cluster.connect();
+  connect(cluster);
+  write_and_read();
+
+  Result result = other.execute("SELECT release_version FROM system.local");
+  ASSERT_EQ(CASS_OK, result.error_code()) << result.error_message();
+  other.close();
+
+  write_and_read();
+}
+
 /**
  * Ensures that when one node (in this case the whole cluster) is terminated and restarted the
  * driver will reconnect without throwing errors. Each stage (connect and reconnect) write and read
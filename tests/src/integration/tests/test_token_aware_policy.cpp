@@ -0,0 +1,102 @@
+/*
+  Copyright (c) DataStax, Inc.
+
+  Licensed under the Apache License, Version 2.0 (the "License");
+  you may not use this file except in compliance with the License.
+  You may obtain a copy of the License at
+
+  http://www.apache.org/licenses/LICENSE-2.0
+
+  Unless required by applicable law or agreed to in writing, software
+  distributed under the License is distributed on an "AS IS" BASIS,
+  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+  See the License for the specific language governing permissions and
+  limitations under the License.
+*/
+
+#include "integration.hpp"
+
+#include <set>
+
+/**
+ * Token-aware routing integration tests
+ */
+class TokenAwarePolicyTest : public Integration {
+public:
+  TokenAwarePolicyTest() {
+    number_dc1_nodes_ = 3;
+    replication_factor_ = 1;
+    is_session_requested_ = false;
+  }
+
+  /**
+   * Execute the statements with tracing enabled and get their coordinators
+   *
+   * Statements are executed one after another before the traces are read, so
+   * the queries reading the traces do not affect the round-robin order.
+   *
+   * @param statements Statements to execute
+   * @return Distinct coordinators of the statements
+   */
+  std::set<std::string> coordinators(std::vector<Statement> statements) {
+    std::vector<Uuid> tracing_ids;
+    for (std::vector<Statement>::iterator it = statements.begin(); it != statements.end(); ++it) {
+      it->set_tracing(true);
+      tracing_ids.push_back(session_.execute(*it).tracing_id());
+    }
+
+    std::set<std::string> coordinators;
+    for (std::vector<Uuid>::iterator it = tracing_ids.begin(); it != tracing_ids.end(); ++it) {
+      // Wait until the trace is complete
+      Future future(cass_session_get_trace(session_.get(), it->value()));
+      future.wait();
+
+      Statement statement("SELECT coordinator FROM system_traces.sessions WHERE session_id = ?",
+                          1);
+      statement.bind<Uuid>(0, *it);
+      Result result = session_.execute(statement);
+      coordinators.insert(result.first_row().next().as<Inet>().str());
+    }
+    return coordinators;
+  }
+};
+
+/**
+ * Verify that only prepared statements are routed token-aware.
+ *
+ * Prepared statements carry the information about the partition key, so they
+ * are always sent to the only replica of the partition. Simple statements are
+ * routed by the child (round-robin) policy.
+ *
+ * @test_category load_balancing_policy:token_aware
+ * @expected_result Prepared statements have a single coordinator, simple statements have many.
+ */
+CASSANDRA_INTEGRATION_TEST_F(TokenAwarePolicyTest, PreparedStatementsOnly) {
+  CHECK_FAILURE;
+
+  cluster_ = default_cluster().with_load_balance_round_robin();
+  cass_cluster_set_token_aware_routing(cluster_.get(), cass_true);
+  connect(cluster_);
+
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_TABLE_FORMAT, table_name_.c_str(), "int", "int"));
+  session_.execute(
+      format_string(CASSANDRA_KEY_VALUE_INSERT_FORMAT, table_name_.c_str(), "1", "1"));
+  std::string select = format_string(CASSANDRA_SELECT_VALUE_FORMAT, table_name_.c_str(), "?");
+
+  Prepared prepared = session_.prepare(select);
+  std::vector<Statement> prepared_statements;
+  std::vector<Statement> simple_statements;
+  for (int i = 0; i < 6; ++i) {
+    Statement prepared_statement = prepared.bind();
+    prepared_statement.bind<Integer>(0, Integer(1));
+    prepared_statements.push_back(prepared_statement);
+
+    Statement simple_statement(select, 1);
+    simple_statement.bind<Integer>(0, Integer(1));
+    simple_statements.push_back(simple_statement);
+  }
+
+  EXPECT_EQ(1u, coordinators(prepared_statements).size());
+  EXPECT_GT(coordinators(simple_statements).size(), 1u);
+}
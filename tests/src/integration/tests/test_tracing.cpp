@@ -41,3 +41,60 @@ CASSANDRA_INTEGRATION_TEST_F(TracingTests, Simple) {
     EXPECT_EQ(tracing_id, session_id);
   }
 }
+
+/**
+ * Get the trace events of a traced request
+ *
+ * This test will execute a statement with tracing enabled and ensure that
+ * `cass_session_get_trace()` returns the events of its trace session.
+ *
+ * @test_category queries:tracing
+ * @expected_result Non-empty events of the trace session are returned
+ */
+CASSANDRA_INTEGRATION_TEST_F(TracingTests, Events) {
+  CHECK_FAILURE;
+
+  Statement statement("SELECT release_version FROM system.local");
+  statement.set_tracing(true);
+  Uuid tracing_id = session_.execute(statement).tracing_id();
+  ASSERT_FALSE(tracing_id.is_null());
+
+  Future future(cass_session_get_trace(session_.get(), tracing_id.value()));
+  future.wait();
+  Result result(future);
+  ASSERT_GT(result.row_count(), 0u);
+  Rows rows = result.rows();
+  for (size_t i = 0; i < rows.row_count(); ++i) {
+    Row row = rows.next();
+    EXPECT_EQ(tracing_id, row.column_by_name<Uuid>("session_id"));
+    EXPECT_FALSE(cass_value_is_null(cass_row_get_column_by_name(row.get(), "activity")));
+  }
+}
+
+/**
+ * Get the tracing ID of a traced prepared statement
+ *
+ * This test will execute a bound statement with tracing enabled and ensure that
+ * the tracing ID is available on the result, as for simple statements.
+ *
+ * @test_category queries:tracing
+ * @expected_result The tracing ID is not null and its trace session exists
+ */
+CASSANDRA_INTEGRATION_TEST_F(TracingTests, Prepared) {
+  CHECK_FAILURE;
+
+  Prepared prepared = session_.prepare("SELECT release_version FROM system.local");
+  Statement statement = prepared.bind();
+  statement.set_tracing(true);
+  Uuid tracing_id = session_.execute(statement).tracing_id();
+  ASSERT_FALSE(tracing_id.is_null());
+
+  Statement select("SELECT * FROM system_traces.sessions WHERE session_id = ?", 1);
+  select.bind(0, tracing_id);
+  Result result = session_.execute(select);
+  ASSERT_GT(result.row_count(), 0u);
+  EXPECT_EQ(tracing_id, result.first_row().column_by_name<Uuid>("session_id"));
+
+  // Without tracing there is no tracing ID
+  EXPECT_TRUE(session_.execute(prepared.bind()).tracing_id().is_null());
+}